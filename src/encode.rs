@@ -0,0 +1,584 @@
+//! Compact serialisation of integer slices.
+//!
+//! Sorted or slowly varying integers, such as timestamps, are first [differentiated](crate::ops::SliceDifferentiate::differentiate) into small deltas,
+//! which are [zigzag encoded](ZigZag) so that small negative deltas are also small unsigned values,
+//! and finally packed as LEB128 varints, using one byte per seven bits.
+
+use crate::{error::Error, private::Sealed};
+
+/// Signed integers that can be zigzag encoded into an unsigned integer of the same width.
+///
+/// Zigzag encoding maps `0, -1, 1, -2, 2, ...` to `0, 1, 2, 3, 4, ...`, so that values of small magnitude become small unsigned values.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::encode::ZigZag;
+///
+/// assert_eq!((-3i32).zigzag_encode(), 5);
+/// assert_eq!(i32::zigzag_decode(5), -3);
+/// ```
+///
+/// This trait is sealed, and only implemented for the primitive signed integers.
+/// [`zigzag_encode_all`] and [`zigzag_decode_all`] rely on this to reinterpret slices between `Self` and [`Unsigned`](ZigZag::Unsigned).
+pub trait ZigZag: Copy + Sealed
+{
+    /// The unsigned integer of the same width.
+    type Unsigned: Copy;
+
+    /// Zigzag encodes the value.
+    fn zigzag_encode(self) -> Self::Unsigned;
+    /// Decodes a zigzag encoded value.
+    fn zigzag_decode(x: Self::Unsigned) -> Self;
+}
+
+impl Sealed for i8
+{
+
+}
+impl ZigZag for i8
+{
+    type Unsigned = u8;
+
+    fn zigzag_encode(self) -> u8
+    {
+        ((self << 1) ^ (self >> (i8::BITS - 1))) as u8
+    }
+    fn zigzag_decode(x: u8) -> Self
+    {
+        (x >> 1) as i8 ^ -((x & 1) as i8)
+    }
+}
+
+impl Sealed for i16
+{
+
+}
+impl ZigZag for i16
+{
+    type Unsigned = u16;
+
+    fn zigzag_encode(self) -> u16
+    {
+        ((self << 1) ^ (self >> (i16::BITS - 1))) as u16
+    }
+    fn zigzag_decode(x: u16) -> Self
+    {
+        (x >> 1) as i16 ^ -((x & 1) as i16)
+    }
+}
+
+impl Sealed for i32
+{
+
+}
+impl ZigZag for i32
+{
+    type Unsigned = u32;
+
+    fn zigzag_encode(self) -> u32
+    {
+        ((self << 1) ^ (self >> (i32::BITS - 1))) as u32
+    }
+    fn zigzag_decode(x: u32) -> Self
+    {
+        (x >> 1) as i32 ^ -((x & 1) as i32)
+    }
+}
+
+impl Sealed for i64
+{
+
+}
+impl ZigZag for i64
+{
+    type Unsigned = u64;
+
+    fn zigzag_encode(self) -> u64
+    {
+        ((self << 1) ^ (self >> (i64::BITS - 1))) as u64
+    }
+    fn zigzag_decode(x: u64) -> Self
+    {
+        (x >> 1) as i64 ^ -((x & 1) as i64)
+    }
+}
+
+impl Sealed for i128
+{
+
+}
+impl ZigZag for i128
+{
+    type Unsigned = u128;
+
+    fn zigzag_encode(self) -> u128
+    {
+        ((self << 1) ^ (self >> (i128::BITS - 1))) as u128
+    }
+    fn zigzag_decode(x: u128) -> Self
+    {
+        (x >> 1) as i128 ^ -((x & 1) as i128)
+    }
+}
+
+impl Sealed for isize
+{
+
+}
+impl ZigZag for isize
+{
+    type Unsigned = usize;
+
+    fn zigzag_encode(self) -> usize
+    {
+        ((self << 1) ^ (self >> (isize::BITS - 1))) as usize
+    }
+    fn zigzag_decode(x: usize) -> Self
+    {
+        (x >> 1) as isize ^ -((x & 1) as isize)
+    }
+}
+
+/// Zigzag encodes a slice of signed integers in-place, and returns it reinterpreted as unsigned integers.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::encode;
+///
+/// let mut x = [0i64, -1, 1, -2, 2];
+///
+/// let y = encode::zigzag_encode_all(&mut x);
+///
+/// assert_eq!(y, [0, 1, 2, 3, 4]);
+///
+/// let z = encode::zigzag_decode_all::<i64>(y);
+///
+/// assert_eq!(z, [0, -1, 1, -2, 2]);
+/// ```
+pub fn zigzag_encode_all<T>(x: &mut [T]) -> &mut [T::Unsigned]
+where
+    T: ZigZag
+{
+    const {
+        assert!(core::mem::size_of::<T>() == core::mem::size_of::<T::Unsigned>() && core::mem::align_of::<T>() == core::mem::align_of::<T::Unsigned>());
+    }
+
+    let l = x.len();
+    let ptr = x.as_mut_ptr();
+    let mut i = 0;
+    while i < l
+    {
+        unsafe {
+            let xi = ptr.add(i);
+            xi.cast::<T::Unsigned>().write(xi.read().zigzag_encode())
+        }
+        i += 1;
+    }
+    unsafe {
+        core::slice::from_raw_parts_mut(ptr.cast(), l)
+    }
+}
+
+/// Decodes a slice of zigzag encoded unsigned integers in-place, and returns it reinterpreted as signed integers.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::encode;
+///
+/// let mut x = [5u32, 0, 6];
+///
+/// let y = encode::zigzag_decode_all::<i32>(&mut x);
+///
+/// assert_eq!(y, [-3, 0, 3]);
+/// ```
+pub fn zigzag_decode_all<T>(x: &mut [T::Unsigned]) -> &mut [T]
+where
+    T: ZigZag
+{
+    const {
+        assert!(core::mem::size_of::<T>() == core::mem::size_of::<T::Unsigned>() && core::mem::align_of::<T>() == core::mem::align_of::<T::Unsigned>());
+    }
+
+    let l = x.len();
+    let ptr = x.as_mut_ptr();
+    let mut i = 0;
+    while i < l
+    {
+        unsafe {
+            let xi = ptr.add(i);
+            xi.cast::<T>().write(T::zigzag_decode(xi.read()))
+        }
+        i += 1;
+    }
+    unsafe {
+        core::slice::from_raw_parts_mut(ptr.cast(), l)
+    }
+}
+
+/// Returns the number of bytes needed to pack a value as a LEB128 varint.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::encode;
+///
+/// assert_eq!(encode::varint_len(0), 1);
+/// assert_eq!(encode::varint_len(127), 1);
+/// assert_eq!(encode::varint_len(128), 2);
+/// assert_eq!(encode::varint_len(u64::MAX), 10);
+/// ```
+pub const fn varint_len(x: u64) -> usize
+{
+    (u64::BITS - (x | 1).leading_zeros()).div_ceil(7) as usize
+}
+
+/// Returns the number of bytes needed to pack a slice as LEB128 varints.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::encode;
+///
+/// assert_eq!(encode::varint_packed_len(&[1, 300, 2]), 4);
+/// ```
+pub const fn varint_packed_len(x: &[u64]) -> usize
+{
+    let mut n = 0;
+    let mut i = 0;
+    while i < x.len()
+    {
+        n += varint_len(x[i]);
+        i += 1;
+    }
+    n
+}
+
+/// Packs a slice as LEB128 varints into `out`.
+///
+/// Returns the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if `out` is too short to hold all the packed values.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::encode;
+///
+/// let x = [1, 300, 2];
+///
+/// let mut bytes = [0; 30];
+/// let n = encode::varint_pack(&x, &mut bytes);
+///
+/// assert_eq!(bytes[..n], [0x01, 0xac, 0x02, 0x02]);
+///
+/// let mut y = [0; 3];
+/// assert_eq!(encode::varint_unpack(&bytes[..n], &mut y), Ok(n));
+///
+/// assert_eq!(y, x);
+/// ```
+pub fn varint_pack(x: &[u64], out: &mut [u8]) -> usize
+{
+    pack_with(x.len(), |i| x[i], out)
+}
+
+/// Unpacks LEB128 varints from `bytes` until `out` is filled.
+///
+/// Returns the number of bytes read, which may be fewer than the length of `bytes`.
+///
+/// # Errors
+///
+/// Returns [`Error::TruncatedInput`] if `bytes` ends before `out` is filled, and [`Error::VarintOverflow`] if a varint does not fit in 64 bits.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::{encode, error::Error};
+///
+/// let bytes = [0x01, 0xac, 0x02];
+///
+/// let mut y = [0; 2];
+/// assert_eq!(encode::varint_unpack(&bytes, &mut y), Ok(3));
+/// assert_eq!(y, [1, 300]);
+///
+/// assert_eq!(encode::varint_unpack(&bytes[..2], &mut y), Err(Error::TruncatedInput {len: 2}));
+/// ```
+pub fn varint_unpack(bytes: &[u8], out: &mut [u64]) -> Result<usize, Error>
+{
+    let mut n = 0;
+    let mut i = 0;
+    while i < out.len()
+    {
+        out[i] = read_varint(bytes, &mut n)?;
+        i += 1;
+    }
+    Ok(n)
+}
+
+/// Packs a slice of signed integers as zigzag encoded LEB128 varints into `out`.
+///
+/// Returns the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if `out` is too short to hold all the packed values.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::encode;
+///
+/// let x = [-1, 64, -65];
+///
+/// let mut bytes = [0; 30];
+/// let n = encode::varint_pack_signed(&x, &mut bytes);
+///
+/// assert_eq!(n, 5);
+///
+/// let mut y = [0; 3];
+/// assert_eq!(encode::varint_unpack_signed(&bytes[..n], &mut y), Ok(n));
+///
+/// assert_eq!(y, x);
+/// ```
+pub fn varint_pack_signed(x: &[i64], out: &mut [u8]) -> usize
+{
+    pack_with(x.len(), |i| x[i].zigzag_encode(), out)
+}
+
+/// Unpacks zigzag encoded LEB128 varints from `bytes` until `out` is filled.
+///
+/// Returns the number of bytes read, which may be fewer than the length of `bytes`.
+///
+/// # Errors
+///
+/// Returns [`Error::TruncatedInput`] if `bytes` ends before `out` is filled, and [`Error::VarintOverflow`] if a varint does not fit in 64 bits.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::encode;
+///
+/// let bytes = [0x01, 0x80, 0x01];
+///
+/// let mut y = [0; 2];
+/// assert_eq!(encode::varint_unpack_signed(&bytes, &mut y), Ok(3));
+/// assert_eq!(y, [-1, 64]);
+/// ```
+pub fn varint_unpack_signed(bytes: &[u8], out: &mut [i64]) -> Result<usize, Error>
+{
+    let mut n = 0;
+    let mut i = 0;
+    while i < out.len()
+    {
+        out[i] = i64::zigzag_decode(read_varint(bytes, &mut n)?);
+        i += 1;
+    }
+    Ok(n)
+}
+
+/// Packs a slice of integers as the zigzag encoded LEB128 varints of its [differences](crate::ops::SliceDifferentiate::differentiate) into `out`.
+///
+/// This is compact for sorted or slowly varying values, such as timestamps.
+/// The differences wrap on overflow, which round-trips losslessly, so any slice can be encoded.
+///
+/// Returns the number of bytes written.
+///
+/// # Panics
+///
+/// Panics if `out` is too short to hold all the packed values.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::encode;
+///
+/// let x = [1_700_000_000, 1_700_000_060, 1_700_000_120, 1_700_000_119];
+///
+/// let mut bytes = [0; 40];
+/// let n = encode::delta_varint_encode(&x, &mut bytes);
+///
+/// assert_eq!(n, 8);
+///
+/// let mut y = [0; 4];
+/// assert_eq!(encode::delta_varint_decode(&bytes[..n], &mut y), Ok(n));
+///
+/// assert_eq!(y, x);
+/// ```
+pub fn delta_varint_encode(x: &[i64], out: &mut [u8]) -> usize
+{
+    pack_with(x.len(), |i| {
+        let prev = if i > 0 {x[i - 1]} else {0};
+        x[i].wrapping_sub(prev).zigzag_encode()
+    }, out)
+}
+
+/// Unpacks the zigzag encoded LEB128 varints of the differences of a slice from `bytes` until `out` is filled, and [integrates](crate::ops::SliceIntegrate::integrate) them.
+///
+/// This is the inverse of [`delta_varint_encode`]. The sums wrap on overflow, so corrupted input never panics.
+/// Returns the number of bytes read, which may be fewer than the length of `bytes`.
+///
+/// # Errors
+///
+/// Returns [`Error::TruncatedInput`] if `bytes` ends before `out` is filled, and [`Error::VarintOverflow`] if a varint does not fit in 64 bits.
+/// On error, the contents of `out` are unspecified.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::{encode, error::Error};
+///
+/// let bytes = [0x14, 0x02, 0x02];
+///
+/// let mut y = [0; 3];
+/// assert_eq!(encode::delta_varint_decode(&bytes, &mut y), Ok(3));
+/// assert_eq!(y, [10, 11, 12]);
+///
+/// let mut y = [0; 4];
+/// assert_eq!(encode::delta_varint_decode(&bytes, &mut y), Err(Error::TruncatedInput {len: 3}));
+/// ```
+pub fn delta_varint_decode(bytes: &[u8], out: &mut [i64]) -> Result<usize, Error>
+{
+    let n = varint_unpack_signed(bytes, out)?;
+    let mut i = 1;
+    while i < out.len()
+    {
+        out[i] = out[i].wrapping_add(out[i - 1]);
+        i += 1;
+    }
+    Ok(n)
+}
+
+/// Packs `len` values, given by `value`, as LEB128 varints into `out`, after checking that they fit.
+fn pack_with<F>(len: usize, value: F, out: &mut [u8]) -> usize
+where
+    F: Fn(usize) -> u64
+{
+    let mut packed_len = 0;
+    let mut i = 0;
+    while i < len
+    {
+        packed_len += varint_len(value(i));
+        i += 1;
+    }
+    assert!(packed_len <= out.len(), "Output is too short to hold all the packed values.");
+
+    let mut n = 0;
+    let mut i = 0;
+    while i < len
+    {
+        n += write_varint(value(i), &mut out[n..]);
+        i += 1;
+    }
+    n
+}
+
+fn write_varint(mut x: u64, out: &mut [u8]) -> usize
+{
+    let mut n = 0;
+    while x >= 0x80
+    {
+        out[n] = x as u8 | 0x80;
+        x >>= 7;
+        n += 1;
+    }
+    out[n] = x as u8;
+    n + 1
+}
+
+fn read_varint(bytes: &[u8], n: &mut usize) -> Result<u64, Error>
+{
+    let offset = *n;
+    let mut x = 0;
+    let mut shift = 0;
+    loop
+    {
+        let Some(&byte) = bytes.get(*n)
+        else
+        {
+            return Err(Error::TruncatedInput {len: bytes.len()})
+        };
+        *n += 1;
+        if shift == 63 && byte > 1
+        {
+            return Err(Error::VarintOverflow {offset})
+        }
+        x |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0
+        {
+            return Ok(x)
+        }
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::error::Error;
+
+    #[test]
+    fn zigzag()
+    {
+        let mut x = [0i8, -1, 1, i8::MIN, i8::MAX];
+
+        let y = super::zigzag_encode_all(&mut x);
+        assert_eq!(y, [0, 1, 2, u8::MAX, u8::MAX - 1]);
+
+        let z = super::zigzag_decode_all::<i8>(y);
+        assert_eq!(z, [0, -1, 1, i8::MIN, i8::MAX]);
+    }
+
+    #[test]
+    fn varint()
+    {
+        let x = [0, 127, 128, 1 << 35, u64::MAX];
+
+        let mut bytes = [0; 50];
+        let n = super::varint_pack(&x, &mut bytes);
+        assert_eq!(n, super::varint_packed_len(&x));
+        assert_eq!(n, 1 + 1 + 2 + 6 + 10);
+
+        let mut y = [0; 5];
+        assert_eq!(super::varint_unpack(&bytes, &mut y), Ok(n));
+        assert_eq!(y, x);
+
+        for len in 0..n
+        {
+            assert_eq!(super::varint_unpack(&bytes[..len], &mut y), Err(Error::TruncatedInput {len}));
+        }
+
+        let overflow = [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+        assert_eq!(super::varint_unpack(&overflow, &mut y[..1]), Err(Error::VarintOverflow {offset: 0}));
+    }
+
+    #[test]
+    fn delta_varint()
+    {
+        let cases: [&[i64]; 4] = [
+            &[],
+            &[i64::MIN/2, -5, 0, 0, 3, i64::MAX/2],
+            &[i64::MIN, i64::MAX, 0],
+            &[i64::MAX, i64::MIN, i64::MAX, -1, i64::MIN]
+        ];
+        for x in cases
+        {
+            let mut bytes = [0; 60];
+            let n = super::delta_varint_encode(x, &mut bytes);
+
+            let mut y = [0; 6];
+            let y = &mut y[..x.len()];
+            assert_eq!(super::delta_varint_decode(&bytes[..n], y), Ok(n));
+            assert_eq!(y, x);
+        }
+
+        // Corrupted input wraps instead of panicking.
+        let bytes = [0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01];
+        let mut y = [0; 2];
+        assert_eq!(super::delta_varint_decode(&bytes, &mut y), Ok(20));
+        assert_eq!(y, [i64::MAX, i64::MAX.wrapping_add(i64::MAX)]);
+    }
+}
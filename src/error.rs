@@ -0,0 +1,71 @@
+use core::fmt::Display;
+
+/// The error type for the fallible operations in this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Error
+{
+    /// The length of the slice was required to be a power of the radix.
+    NotPowerOf
+    {
+        len: usize,
+        radix: usize
+    },
+    /// The radix was less than two.
+    InvalidRadix
+    {
+        radix: usize
+    },
+    /// The product of the radices did not equal the length of the slice.
+    RadixProductMismatch
+    {
+        len: usize
+    },
+    /// The index to split at was greater than the length of the slice.
+    SplitOutOfBounds
+    {
+        mid: usize,
+        len: usize
+    },
+    /// A lane did not have the length that spreading the slice would produce.
+    LaneLengthMismatch
+    {
+        lane: usize,
+        len: usize,
+        expected: usize
+    },
+    /// There were no lanes to spread across or gather from.
+    NoLanes,
+    /// The encoded input ended before all values were decoded.
+    TruncatedInput
+    {
+        len: usize
+    },
+    /// A varint did not fit in 64 bits.
+    VarintOverflow
+    {
+        offset: usize
+    }
+}
+
+impl Display for Error
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        match *self
+        {
+            Error::NotPowerOf { len, radix } => write!(f, "length {len} is not a power of {radix}"),
+            Error::InvalidRadix { radix } => write!(f, "radix {radix} is less than two"),
+            Error::RadixProductMismatch { len } => write!(f, "product of radices does not equal length {len}"),
+            Error::SplitOutOfBounds { mid, len } => write!(f, "split index {mid} is out of bounds for length {len}"),
+            Error::LaneLengthMismatch { lane, len, expected } => write!(f, "lane {lane} has length {len}, expected {expected}"),
+            Error::NoLanes => write!(f, "there are no lanes"),
+            Error::TruncatedInput { len } => write!(f, "input of {len} bytes ended before all values were decoded"),
+            Error::VarintOverflow { offset } => write!(f, "varint at byte {offset} does not fit in 64 bits")
+        }
+    }
+}
+
+impl core::error::Error for Error
+{
+
+}
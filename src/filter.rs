@@ -0,0 +1,385 @@
+use core::ops::{Add, Mul, Sub};
+
+use crate::ops::SliceShift;
+
+/// A streaming filter, which processes one sample at a time and keeps its history in a caller-owned state slice.
+///
+/// Since the state outlives each call, a signal may be filtered in blocks of any size.
+pub trait Filter<T>
+{
+    /// Filters a single sample, and returns the output sample.
+    fn filter(&mut self, x: T) -> T;
+
+    /// Clears the history of the filter.
+    fn reset(&mut self)
+    where
+        T: Default;
+
+    /// Filters a signal in-place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::filter::{Filter, Fir};
+    ///
+    /// let taps = [0.5, 0.5];
+    /// let mut state = [0.0; 2];
+    /// let mut fir = Fir::new(&taps, &mut state);
+    ///
+    /// let mut x = [1.0, 3.0, 5.0];
+    /// fir.filter_in_place(&mut x);
+    ///
+    /// assert_eq!(x, [0.5, 2.0, 4.0]);
+    /// ```
+    fn filter_in_place(&mut self, x: &mut [T])
+    where
+        T: Copy
+    {
+        let l = x.len();
+        let mut i = 0;
+        while i < l
+        {
+            x[i] = self.filter(x[i]);
+            i += 1;
+        }
+    }
+
+    /// Filters the signal `x`, and writes the output to `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` and `y` have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::filter::{Filter, Fir};
+    ///
+    /// let taps = [1.0, -1.0];
+    /// let mut state = [0.0; 2];
+    /// let mut fir = Fir::new(&taps, &mut state);
+    ///
+    /// let x = [1.0, 3.0, 6.0];
+    /// let mut y = [0.0; 3];
+    /// fir.filter_into(&x, &mut y);
+    ///
+    /// assert_eq!(y, [1.0, 2.0, 3.0]);
+    /// ```
+    fn filter_into(&mut self, x: &[T], y: &mut [T])
+    where
+        T: Copy
+    {
+        assert_eq!(x.len(), y.len(), "Input and output must have the same length.");
+        let l = x.len();
+        let mut i = 0;
+        while i < l
+        {
+            y[i] = self.filter(x[i]);
+            i += 1;
+        }
+    }
+}
+
+/// The structure used to realize a [`Biquad`].
+///
+/// All forms have the same transfer function, but differ in how much state they need and in their numerical behaviour.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BiquadForm
+{
+    /// Direct form I, which keeps the two previous inputs and the two previous outputs.
+    DirectFormI,
+    /// Direct form II, which keeps two delayed values of the internal signal.
+    DirectFormII,
+    /// Transposed direct form II, which keeps two partial sums.
+    TransposedDirectFormII
+}
+
+impl BiquadForm
+{
+    /// Returns the length of the state slice that a biquad of this form needs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::filter::BiquadForm;
+    ///
+    /// assert_eq!(BiquadForm::DirectFormI.state_len(), 4);
+    /// assert_eq!(BiquadForm::TransposedDirectFormII.state_len(), 2);
+    /// ```
+    pub const fn state_len(self) -> usize
+    {
+        match self
+        {
+            BiquadForm::DirectFormI => 4,
+            BiquadForm::DirectFormII | BiquadForm::TransposedDirectFormII => 2
+        }
+    }
+}
+
+/// A second-order IIR filter section, with the transfer function
+/// `H(z) = (b0 + b1/z + b2/z²)/(1 + a1/z + a2/z²)`.
+///
+/// The signal type `T` may differ from the coefficient type `C`, so that for instance a complex signal can be filtered with real coefficients.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::filter::{Biquad, BiquadForm, Filter};
+///
+/// // A leaky integrator, y[n] = x[n] + 0.5 y[n - 1]
+/// let form = BiquadForm::TransposedDirectFormII;
+/// let mut state = [0.0; 2];
+/// let mut biquad = Biquad::new(form, [1.0, 0.0, 0.0], [-0.5, 0.0], &mut state);
+///
+/// let mut x = [1.0, 0.0, 0.0, 0.0];
+/// biquad.filter_in_place(&mut x);
+///
+/// assert_eq!(x, [1.0, 0.5, 0.25, 0.125]);
+/// ```
+pub struct Biquad<'a, T, C>
+{
+    form: BiquadForm,
+    b: [C; 3],
+    a: [C; 2],
+    state: &'a mut [T]
+}
+
+impl<'a, T, C> Biquad<'a, T, C>
+{
+    /// Creates a biquad with the numerator coefficients `b = [b0, b1, b2]` and the denominator coefficients `a = [a1, a2]`, where `a0` is normalized to one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `state` is not [`form.state_len()`](BiquadForm::state_len).
+    pub fn new(form: BiquadForm, b: [C; 3], a: [C; 2], state: &'a mut [T]) -> Self
+    {
+        assert_eq!(state.len(), form.state_len(), "State length does not match the biquad form.");
+        Self {
+            form,
+            b,
+            a,
+            state
+        }
+    }
+
+    /// Returns the form of the biquad.
+    pub fn form(&self) -> BiquadForm
+    {
+        self.form
+    }
+
+    /// Returns the state of the biquad.
+    pub fn state(&self) -> &[T]
+    {
+        self.state
+    }
+}
+
+impl<'a, T, C> Filter<T> for Biquad<'a, T, C>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<C, Output = T>,
+    C: Copy
+{
+    fn filter(&mut self, x: T) -> T
+    {
+        let [b0, b1, b2] = self.b;
+        let [a1, a2] = self.a;
+        let s = &mut *self.state;
+        match self.form
+        {
+            BiquadForm::DirectFormI => {
+                let y = x*b0 + s[0]*b1 + s[1]*b2 - s[2]*a1 - s[3]*a2;
+                let mut x = x;
+                let mut y1 = y;
+                let (xs, ys) = s.split_at_mut(2);
+                xs.shift_right(&mut x);
+                ys.shift_right(&mut y1);
+                y
+            },
+            BiquadForm::DirectFormII => {
+                let mut w = x - s[0]*a1 - s[1]*a2;
+                let y = w*b0 + s[0]*b1 + s[1]*b2;
+                s.shift_right(&mut w);
+                y
+            },
+            BiquadForm::TransposedDirectFormII => {
+                let y = x*b0 + s[0];
+                s[0] = x*b1 - y*a1 + s[1];
+                s[1] = x*b2 - y*a2;
+                y
+            }
+        }
+    }
+
+    fn reset(&mut self)
+    where
+        T: Default
+    {
+        self.state.fill(T::default())
+    }
+}
+
+/// A finite impulse response filter, `y[n] = Σ taps[k] x[n - k]`.
+///
+/// The previous inputs are kept in a delay line in the caller-owned state slice, which must have the same length as the taps.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::filter::{Filter, Fir};
+///
+/// let taps = [1.0, 2.0, 3.0];
+/// let mut state = [0.0; 3];
+/// let mut fir = Fir::new(&taps, &mut state);
+///
+/// let mut x = [1.0, 0.0, 0.0, 0.0];
+/// fir.filter_in_place(&mut x);
+///
+/// assert_eq!(x, [1.0, 2.0, 3.0, 0.0]);
+/// ```
+pub struct Fir<'a, T, C>
+{
+    taps: &'a [C],
+    state: &'a mut [T]
+}
+
+impl<'a, T, C> Fir<'a, T, C>
+{
+    /// Creates an FIR filter with the given taps.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no taps, or if `state` is not the same length as `taps`.
+    pub fn new(taps: &'a [C], state: &'a mut [T]) -> Self
+    {
+        assert!(!taps.is_empty(), "FIR filter must have at least one tap.");
+        assert_eq!(state.len(), taps.len(), "State length must equal the number of taps.");
+        Self {
+            taps,
+            state
+        }
+    }
+
+    /// Returns the taps of the filter.
+    pub fn taps(&self) -> &[C]
+    {
+        self.taps
+    }
+
+    /// Returns the delay line of the filter, with the most recent input first.
+    pub fn state(&self) -> &[T]
+    {
+        self.state
+    }
+}
+
+impl<'a, T, C> Filter<T> for Fir<'a, T, C>
+where
+    T: Copy + Add<Output = T> + Mul<C, Output = T>,
+    C: Copy
+{
+    fn filter(&mut self, x: T) -> T
+    {
+        let mut x = x;
+        self.state.shift_right(&mut x);
+
+        let l = self.taps.len();
+        let mut y = self.state[0]*self.taps[0];
+        let mut k = 1;
+        while k < l
+        {
+            y = y + self.state[k]*self.taps[k];
+            k += 1;
+        }
+        y
+    }
+
+    fn reset(&mut self)
+    where
+        T: Default
+    {
+        self.state.fill(T::default())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::{Biquad, BiquadForm, Filter, Fir};
+
+    const B: [f64; 3] = [0.2, 0.3, -0.1];
+    const A: [f64; 2] = [-0.6, 0.25];
+
+    fn signal() -> [f64; 32]
+    {
+        core::array::from_fn(|i| ((i*7 + 3) % 11) as f64 - 5.0)
+    }
+
+    #[test]
+    fn biquad_forms()
+    {
+        let x = signal();
+
+        let mut reference = [0.0; 32];
+        let mut i = 0;
+        while i < x.len()
+        {
+            let x1 = if i >= 1 {x[i - 1]} else {0.0};
+            let x2 = if i >= 2 {x[i - 2]} else {0.0};
+            let y1 = if i >= 1 {reference[i - 1]} else {0.0};
+            let y2 = if i >= 2 {reference[i - 2]} else {0.0};
+            reference[i] = B[0]*x[i] + B[1]*x1 + B[2]*x2 - A[0]*y1 - A[1]*y2;
+            i += 1;
+        }
+
+        for form in [BiquadForm::DirectFormI, BiquadForm::DirectFormII, BiquadForm::TransposedDirectFormII]
+        {
+            let mut state = [0.0; 4];
+            let mut biquad = Biquad::new(form, B, A, &mut state[..form.state_len()]);
+
+            // Filter in two blocks, to check that the state carries over.
+            let mut y = x;
+            let (first, second) = y.split_at_mut(13);
+            biquad.filter_in_place(first);
+            biquad.filter_in_place(second);
+
+            assert!(y.iter().zip(reference.iter()).all(|(a, b)| (a - b).abs() < 1e-9), "{form:?}");
+        }
+    }
+
+    #[test]
+    fn fir()
+    {
+        let taps = [0.5, -1.0, 0.25, 2.0];
+        let mut state = [0.0; 4];
+        let mut fir = Fir::new(&taps, &mut state);
+
+        let mut impulse = [0.0; 6];
+        impulse[0] = 1.0;
+        let mut y = [0.0; 6];
+        fir.filter_into(&impulse, &mut y);
+
+        assert_eq!(y, [0.5, -1.0, 0.25, 2.0, 0.0, 0.0]);
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn complex()
+    {
+        use num_complex::Complex;
+
+        let x = signal();
+        let mut re = x;
+        let mut im = x.map(|x| Complex::new(0.0, x));
+
+        for form in [BiquadForm::DirectFormI, BiquadForm::DirectFormII, BiquadForm::TransposedDirectFormII]
+        {
+            let mut state = [0.0; 4];
+            let mut complex_state = [Complex::new(0.0, 0.0); 4];
+            Biquad::new(form, B, A, &mut state[..form.state_len()]).filter_in_place(&mut re);
+            Biquad::new(form, B, A, &mut complex_state[..form.state_len()]).filter_in_place(&mut im);
+        }
+
+        assert!(re.iter().zip(im.iter()).all(|(a, b)| b.re == 0.0 && (a - b.im).abs() < 1e-9));
+    }
+}
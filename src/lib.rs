@@ -58,11 +58,57 @@
 //! assert_eq!(x[i], 5);
 //! ```
 //!
+//! # group_by / runs / split_on
+//!
+//! - [`group_by`](crate::ops::SliceGroup::group_by) / [`group_by_mut`](crate::ops::SliceGroup::group_by_mut)
+//! - [`runs`](crate::ops::SliceGroup::runs) / [`runs_mut`](crate::ops::SliceGroup::runs_mut)
+//! - [`split_on`](crate::ops::SliceGroup::split_on) / [`split_on_mut`](crate::ops::SliceGroup::split_on_mut)
+//! - [`splitn_on`](crate::ops::SliceGroup::splitn_on) / [`splitn_on_mut`](crate::ops::SliceGroup::splitn_on_mut)
+//!
+//! ```rust
+//! use slice_ops::ops::*;
+//!
+//! let x = [1, 2, 3, 2, 3, 4, 0];
+//!
+//! let mut groups = x.group_by(|a, b| a < b);
+//!
+//! assert_eq!(groups.next(), Some(&[1, 2, 3][..]));
+//! assert_eq!(groups.next(), Some(&[2, 3, 4][..]));
+//! assert_eq!(groups.next(), Some(&[0][..]));
+//! assert_eq!(groups.next(), None);
+//! ```
+//!
+//! # rle_encode / rle_decode
+//!
+//! - [`run_lengths`](crate::ops::SliceRle::run_lengths)
+//! - [`rle_encode_into`](crate::ops::SliceRle::rle_encode_into) / [`rle_encode_boxed`](crate::ops::SliceRle::rle_encode_boxed)
+//! - [`rle_decode_into`](crate::ops::SliceRleDecode::rle_decode_into) / [`rle_decode_boxed`](crate::ops::SliceRleDecode::rle_decode_boxed)
+//! - [`rle_decoded_len`](crate::ops::SliceRleDecode::rle_decoded_len)
+//!
+//! ```rust
+//! use slice_ops::ops::*;
+//!
+//! let x = [7, 7, 7, 1, 3, 3];
+//!
+//! let mut runs = [(0, 0); 3];
+//! x.rle_encode_into(&mut runs);
+//!
+//! assert_eq!(runs, [(7, 3), (1, 1), (3, 2)]);
+//!
+//! let mut y = [0; 6];
+//! runs.rle_decode_into(&mut y);
+//!
+//! assert_eq!(y, x);
+//! ```
+//!
 //! # argmax / argmin
 //!
 //! - [`argmax`](crate::ops::SliceArgMinMax::argmax) / [`argmin`](crate::ops::SliceArgMinMax::argmin)
 //! - [`argmax_by`](crate::ops::SliceArgMinMax::argmax_by) / [`argmin_by`](crate::ops::SliceArgMinMax::argmin_by)
 //! - [`argmax_by_key`](crate::ops::SliceArgMinMax::argmax_by_key) / [`argmin_by_key`](crate::ops::SliceArgMinMax::argmin_by_key)
+//! - [`argminmax`](crate::ops::SliceArgMinMax::argminmax) / [`argminmax_by`](crate::ops::SliceArgMinMax::argminmax_by) / [`argminmax_by_key`](crate::ops::SliceArgMinMax::argminmax_by_key)
+//! - [`argmax_float`](crate::ops::SliceArgMinMax::argmax_float) / [`argmin_float`](crate::ops::SliceArgMinMax::argmin_float)
+//! - [`argmax_by_key_float`](crate::ops::SliceArgMinMax::argmax_by_key_float) / [`argmin_by_key_float`](crate::ops::SliceArgMinMax::argmin_by_key_float)
 //!
 //! ```rust
 //! use slice_ops::ops::*;
@@ -75,6 +121,51 @@
 //! assert_eq!(i, 3);
 //! ```
 //!
+//! # argtopk / argbottomk
+//!
+//! - [`argtopk`](crate::ops::SliceArgTopK::argtopk) / [`argbottomk`](crate::ops::SliceArgTopK::argbottomk)
+//! - [`argtopk_by`](crate::ops::SliceArgTopK::argtopk_by) / [`argbottomk_by`](crate::ops::SliceArgTopK::argbottomk_by)
+//! - [`argtopk_by_key`](crate::ops::SliceArgTopK::argtopk_by_key) / [`argbottomk_by_key`](crate::ops::SliceArgTopK::argbottomk_by_key)
+//! - [`argtopk_boxed`](crate::ops::SliceArgTopK::argtopk_boxed) / [`argbottomk_boxed`](crate::ops::SliceArgTopK::argbottomk_boxed)
+//!
+//! ```rust
+//! use slice_ops::ops::*;
+//!
+//! let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+//!
+//! let mut i = [0; 3];
+//!
+//! x.argtopk(3, &mut i, true);
+//!
+//! assert_eq!(i, [5, 7, 4]);
+//! ```
+//!
+//! # argsort
+//!
+//! - [`argsort`](crate::ops::SliceArgSort::argsort) / [`argsort_unstable`](crate::ops::SliceArgSort::argsort_unstable)
+//! - [`argsort_by`](crate::ops::SliceArgSort::argsort_by) / [`argsort_unstable_by`](crate::ops::SliceArgSort::argsort_unstable_by)
+//! - [`argsort_by_key`](crate::ops::SliceArgSort::argsort_by_key) / [`argsort_unstable_by_key`](crate::ops::SliceArgSort::argsort_unstable_by_key)
+//! - [`argsort_boxed`](crate::ops::SliceArgSort::argsort_boxed) / [`argsort_unstable_boxed`](crate::ops::SliceArgSort::argsort_unstable_boxed)
+//! - [`apply_argsort`](crate::ops::SliceArgSort::apply_argsort)
+//!
+//! ```rust
+//! use slice_ops::ops::*;
+//!
+//! let mut keys = [3, 1, 4, 1, 5];
+//! let mut names = ["c", "a", "d", "b", "e"];
+//!
+//! let mut perm = [0; 5];
+//! keys.argsort(&mut perm);
+//!
+//! assert_eq!(perm, [1, 3, 0, 2, 4]);
+//!
+//! keys.apply_argsort(&mut perm);
+//! names.apply_argsort(&mut perm);
+//!
+//! assert_eq!(keys, [1, 1, 3, 4, 5]);
+//! assert_eq!(names, ["a", "b", "c", "d", "e"]);
+//! ```
+//!
 //! # visit
 //!
 //! - [`visit`](crate::ops::SliceVisit::visit) / [`visit_mut`](crate::ops::SliceVisit::visit_mut)
@@ -144,6 +235,11 @@
 //! # spread
 //!
 //! - [`spread`](crate::ops::SliceSpread::spread) / [`spread_mut`](crate::ops::SliceSpread::spread_mut)
+//! - [`unspread_from`](crate::ops::SliceSpread::unspread_from) / [`unspread_from_dyn`](crate::ops::SliceSpread::unspread_from_dyn)
+//!
+//! **Breaking change:** `spread` and `spread_mut` now return [`Strided`](crate::strided::Strided) / [`StridedMut`](crate::strided::StridedMut) views instead of `&[Padded<T, M>]` / `&mut [Padded<T, M>]`.
+//! The padded slices reached past the end of the operand for lengths not divisible by `M`, which was unsound.
+//! Lanes now yield `&T` rather than `&Padded<T, M>`, so `**x` becomes `*x`.
 //!
 //! ```rust
 //! #![feature(generic_const_exprs)]
@@ -159,10 +255,113 @@
 //! assert_eq!(even, [2]);
 //! ```
 //!
+//! # spread_dyn
+//!
+//! - [`spread_dyn`](crate::ops::SliceSpread::spread_dyn) / [`spread_dyn_mut`](crate::ops::SliceSpread::spread_dyn_mut)
+//! - [`Strided`](crate::strided::Strided) / [`StridedMut`](crate::strided::StridedMut)
+//!
+//! ```rust
+//! use slice_ops::ops::*;
+//!
+//! let mut arr = [1, 2, 3, 4, 5, 6, 7, 8];
+//! let channels = 2;
+//!
+//! for (gain, mut lane) in [10, 100].into_iter().zip(arr.spread_dyn_mut(channels))
+//! {
+//!     lane.mul_assign_all(gain);
+//! }
+//!
+//! assert_eq!(arr, [10, 200, 30, 400, 50, 600, 70, 800]);
+//! ```
+//!
+//! # ring_buffer
+//!
+//! - [`RingBuffer`](crate::ring_buffer::RingBuffer) / [`ArrayRingBuffer`](crate::ring_buffer::ArrayRingBuffer)
+//!
+//! ```rust
+//! use slice_ops::ring_buffer::RingBuffer;
+//!
+//! let mut history = [0; 4];
+//! let mut ring = RingBuffer::new(&mut history);
+//!
+//! for x in 1..=6
+//! {
+//!     ring.push_back(x);
+//! }
+//!
+//! assert_eq!(ring.as_slices(), (&[3, 4][..], &[5, 6][..]));
+//! assert_eq!(ring.argmax(), Some(3));
+//! ```
+//!
+//! # filter
+//!
+//! - [`Fir`](crate::filter::Fir) / [`Biquad`](crate::filter::Biquad)
+//! - [`filter_in_place`](crate::filter::Filter::filter_in_place) / [`filter_into`](crate::filter::Filter::filter_into)
+//!
+//! ```rust
+//! use slice_ops::filter::{Biquad, BiquadForm, Filter};
+//!
+//! let form = BiquadForm::TransposedDirectFormII;
+//! let mut state = [0.0; 2];
+//! let mut biquad = Biquad::new(form, [1.0, 0.0, 0.0], [-0.5, 0.0], &mut state);
+//!
+//! let mut x = [1.0, 0.0, 0.0, 0.0];
+//! biquad.filter_in_place(&mut x);
+//!
+//! assert_eq!(x, [1.0, 0.5, 0.25, 0.125]);
+//! ```
+//!
+//! # encode
+//!
+//! - [`zigzag_encode_all`](crate::encode::zigzag_encode_all) / [`zigzag_decode_all`](crate::encode::zigzag_decode_all)
+//! - [`varint_pack`](crate::encode::varint_pack) / [`varint_unpack`](crate::encode::varint_unpack)
+//! - [`varint_pack_signed`](crate::encode::varint_pack_signed) / [`varint_unpack_signed`](crate::encode::varint_unpack_signed)
+//! - [`delta_varint_encode`](crate::encode::delta_varint_encode) / [`delta_varint_decode`](crate::encode::delta_varint_decode)
+//!
+//! ```rust
+//! use slice_ops::encode;
+//!
+//! let timestamps = [1_700_000_000, 1_700_000_060, 1_700_000_120, 1_700_000_119];
+//!
+//! let mut bytes = [0; 40];
+//! let n = encode::delta_varint_encode(&timestamps, &mut bytes);
+//!
+//! assert_eq!(n, 8);
+//!
+//! let mut decoded = [0; 4];
+//! encode::delta_varint_decode(&bytes[..n], &mut decoded).unwrap();
+//!
+//! assert_eq!(decoded, timestamps);
+//! ```
+//!
+//! # interleave / deinterleave
+//!
+//! - [`interleave`](crate::ops::SliceInterleave::interleave) / [`deinterleave`](crate::ops::SliceInterleave::deinterleave)
+//! - [`faro_out_shuffle`](crate::ops::SliceInterleave::faro_out_shuffle) / [`faro_in_shuffle`](crate::ops::SliceInterleave::faro_in_shuffle)
+//!
+//! ```rust
+//! use slice_ops::ops::*;
+//!
+//! let mut x = ['L', 'R', 'L', 'R', 'L', 'R'];
+//!
+//! x.deinterleave(2);
+//!
+//! assert_eq!(x, ['L', 'L', 'L', 'R', 'R', 'R']);
+//!
+//! x.interleave(2);
+//!
+//! assert_eq!(x, ['L', 'R', 'L', 'R', 'L', 'R']);
+//! ```
+//!
 //! # bit_rev_permutation
 //!
 //! - [`bit_rev_permutation`](crate::ops::SlicePermute::bit_rev_permutation)
 //! - [`digit_rev_permutation`](crate::ops::SlicePermute::digit_rev_permutation)
+//! - [`mixed_radix_digit_rev_permutation`](crate::ops::SlicePermute::mixed_radix_digit_rev_permutation)
+//! - [`apply_permutation`](crate::ops::SlicePermute::apply_permutation) / [`apply_inverse_permutation`](crate::ops::SlicePermute::apply_inverse_permutation)
+//! - [`next_permutation`](crate::ops::SlicePermute::next_permutation) / [`prev_permutation`](crate::ops::SlicePermute::prev_permutation)
+//! - [`permutation_rank`](crate::ops::SlicePermute::permutation_rank) / [`from_rank`](crate::ops::SlicePermute::from_rank)
+//! - [`visit_permutations`](crate::ops::SlicePermute::visit_permutations) / [`try_visit_permutations`](crate::ops::SlicePermute::try_visit_permutations)
 //!
 //! ```rust
 //! use slice_ops::ops::*;
@@ -188,11 +387,77 @@
 //! assert_eq!(arr, [0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100])
 //! ```
 //!
+//! # transpose
+//!
+//! - [`transpose`](crate::ops::SliceTranspose::transpose) / [`transpose_into`](crate::ops::SliceTranspose::transpose_into)
+//!
+//! ```rust
+//! use slice_ops::ops::*;
+//!
+//! let mut matrix = [
+//!     1, 2, 3,
+//!     4, 5, 6
+//! ];
+//!
+//! matrix.transpose(2, 3);
+//!
+//! assert_eq!(matrix, [
+//!     1, 4,
+//!     2, 5,
+//!     3, 6
+//! ]);
+//! ```
+//!
+//! # split_array_ref / rsplit_array_ref / split_array_chunks
+//!
+//! - [`split_array_ref`](crate::ops::SliceSplit::split_array_ref) / [`split_array_mut`](crate::ops::SliceSplit::split_array_mut)
+//! - [`rsplit_array_ref`](crate::ops::SliceSplit::rsplit_array_ref) / [`rsplit_array_mut`](crate::ops::SliceSplit::rsplit_array_mut)
+//! - [`split_array_chunks`](crate::ops::SliceSplit::split_array_chunks) / [`split_array_chunks_mut`](crate::ops::SliceSplit::split_array_chunks_mut)
+//!
+//! ```rust
+//! use slice_ops::ops::*;
+//!
+//! let arr = [1, 2, 3, 4, 5];
+//!
+//! let (header, body) = arr.split_array_ref::<2>().unwrap();
+//! let (pairs, rest) = body.split_array_chunks::<2>();
+//!
+//! assert_eq!(header, &[1, 2]);
+//! assert_eq!(pairs, &[[3, 4]]);
+//! assert_eq!(rest, &[5]);
+//! ```
+//!
+//! # split_at_many / split_into_n / split_by_ratio
+//!
+//! - [`split_at_many`](crate::ops::SliceSplit::split_at_many) / [`split_at_many_mut`](crate::ops::SliceSplit::split_at_many_mut)
+//! - [`split_into_n`](crate::ops::SliceSplit::split_into_n) / [`split_into_n_mut`](crate::ops::SliceSplit::split_into_n_mut)
+//! - [`split_by_ratio`](crate::ops::SliceSplit::split_by_ratio) / [`split_by_ratio_mut`](crate::ops::SliceSplit::split_by_ratio_mut)
+//!
+//! ```rust
+//! use slice_ops::ops::*;
+//!
+//! let mut arr = [0; 7];
+//!
+//! for (i, part) in arr.split_into_n_mut(3).enumerate()
+//! {
+//!     part.fill(i);
+//! }
+//!
+//! assert_eq!(arr, [0, 0, 0, 1, 1, 2, 2]);
+//! ```
+//!
 //! # trim
 //!
 //! - [`trim`](crate::ops::SliceTrim::trim) / [`trim_mut`](crate::ops::SliceTrim::trim_mut)
 //! - [`trim_front`](crate::ops::SliceTrim::trim_front) / [`trim_front_mut`](crate::ops::SliceTrim::trim_front_mut)
 //! - [`trim_back`](crate::ops::SliceTrim::trim_back) / [`trim_back_mut`](crate::ops::SliceTrim::trim_back_mut)
+//! - [`trim_at_most`](crate::ops::SliceTrim::trim_at_most) / [`trim_at_most_mut`](crate::ops::SliceTrim::trim_at_most_mut)
+//! - [`trim_matches`](crate::ops::SliceTrim::trim_matches) / [`trim_matches_mut`](crate::ops::SliceTrim::trim_matches_mut)
+//! - [`trim_matches_any`](crate::ops::SliceTrim::trim_matches_any) / [`trim_matches_any_mut`](crate::ops::SliceTrim::trim_matches_any_mut)
+//! - [`trim_prefix_slice`](crate::ops::SliceTrim::trim_prefix_slice) / [`trim_prefix_slice_mut`](crate::ops::SliceTrim::trim_prefix_slice_mut)
+//! - [`trim_suffix_slice`](crate::ops::SliceTrim::trim_suffix_slice) / [`trim_suffix_slice_mut`](crate::ops::SliceTrim::trim_suffix_slice_mut)
+//! - [`trim_range`](crate::ops::SliceTrim::trim_range)
+//! - [`trim_in_place`](crate::ops::VecTrim::trim_in_place) / [`trim_front_in_place`](crate::ops::VecTrim::trim_front_in_place) / [`trim_back_in_place`](crate::ops::VecTrim::trim_back_in_place) for `Vec` and `Box<[T]>` (requires `alloc`)
 //!
 //! ```rust
 //! use slice_ops::ops::*;
@@ -209,10 +474,15 @@ extern crate alloc;
 
 moddef::moddef!(
     pub mod {
+        encode,
+        error,
+        filter,
         ops,
         future,
         padded,
-        spec
+        ring_buffer,
+        spec,
+        strided
     },
     mod {
         private
@@ -240,6 +510,61 @@ pub const fn is_power_of(n: usize, r: usize) -> bool
     r.pow(n.ilog(r)) == n
 }
 
+/// Writes a factorization of `len` into `radices`, suitable for [`mixed_radix_digit_rev_permutation`](crate::ops::SlicePermute::mixed_radix_digit_rev_permutation),
+/// and returns the part of `radices` that was written.
+/// 
+/// Factors of 4 are preferred, followed by 2, and then the odd prime factors in increasing order.
+/// 
+/// # Panics
+/// 
+/// Panics if `radices` is too short. A buffer with a length of [`usize::BITS`] is always long enough.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// let mut radices = [0; usize::BITS as usize];
+/// 
+/// assert_eq!(slice_ops::radix_factors(12, &mut radices), [4, 3]);
+/// assert_eq!(slice_ops::radix_factors(360, &mut radices), [4, 2, 3, 3, 5]);
+/// assert_eq!(slice_ops::radix_factors(1, &mut radices), []);
+/// ```
+pub fn radix_factors(mut len: usize, radices: &mut [usize]) -> &[usize]
+{
+    let mut n = 0;
+    let mut push = |radix: usize| {
+        assert!(n < radices.len(), "Radix buffer is too short.");
+        radices[n] = radix;
+        n += 1;
+    };
+
+    while len % 4 == 0 && len > 0
+    {
+        len /= 4;
+        push(4);
+    }
+    while len % 2 == 0 && len > 0
+    {
+        len /= 2;
+        push(2);
+    }
+    let mut p = 3;
+    while p <= len / p
+    {
+        while len % p == 0
+        {
+            len /= p;
+            push(p);
+        }
+        p += 2;
+    }
+    if len > 1
+    {
+        push(len);
+    }
+
+    &radices[..n]
+}
+
 #[cfg(test)]
 mod tests
 {
@@ -1,7 +1,9 @@
-use core::{cmp::Ordering, marker::Destruct};
+use core::{cell::RefCell, cmp::Ordering, marker::Destruct};
 
 use slice_trait::Slice;
 
+use crate::spec::FloatKey;
+
 use super::SliceArgReduce;
 
 #[const_trait]
@@ -11,6 +13,9 @@ pub trait SliceArgMinMax<T>: Slice<Item = T>
     /// 
     /// If there are multiple maxima, only the first will have its index returned.
     /// 
+    /// Incomparable values, such as NaN, are never picked over the current maximum, and nothing is ever picked over them. The result therefore depends on
+    /// where they are in the slice. Use [`argmax_float`](SliceArgMinMax::argmax_float) to handle NaN explicitly.
+    /// 
     /// # Example
     /// 
     /// ```rust
@@ -30,6 +35,9 @@ pub trait SliceArgMinMax<T>: Slice<Item = T>
     /// 
     /// If there are multiple minimums, only the first will have its index returned.
     /// 
+    /// Incomparable values, such as NaN, are never picked over the current minimum, and nothing is ever picked over them. The result therefore depends on
+    /// where they are in the slice. Use [`argmin_float`](SliceArgMinMax::argmin_float) to handle NaN explicitly.
+    /// 
     /// # Example
     /// 
     /// ```rust
@@ -135,6 +143,164 @@ pub trait SliceArgMinMax<T>: Slice<Item = T>
         F: FnMut(&'a T) -> B + ~const Destruct,
         B: PartialOrd,
         T: 'a;
+    /// Finds the indices of both the minimum and the maximum value in the slice, in a single pass.
+    /// 
+    /// This requires between `3n/2` and `3n` comparisons, as opposed to `2n` when calling [`argmin`](SliceArgMinMax::argmin) and [`argmax`](SliceArgMinMax::argmax) separately.
+    /// See [`argreduce2`](crate::ops::SliceArgReduce::argreduce2) for how the cost depends on the data.
+    /// 
+    /// If there are multiple minimums or maxima, only the first of each will have its index returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// //                v             v
+    /// let x = [1, 5, 5, 6, 2, -1, 0, -4, -1, 6];
+    /// 
+    /// let (i_min, i_max) = x.argminmax().unwrap();
+    /// 
+    /// assert_eq!(i_min, 7);
+    /// assert_eq!(i_max, 3);
+    /// ```
+    fn argminmax(&self) -> Option<(usize, usize)>
+    where
+        T: PartialOrd<T>;
+    /// Finds the indices of both the minimum and the maximum value in the slice, in a single pass, given a comparison predicate.
+    /// 
+    /// If there are multiple minimums or maxima, only the first of each will have its index returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [1, 5, 5, 6, 2, -1, 0, -4, -1, 6];
+    /// 
+    /// let f = Ord::cmp;
+    /// 
+    /// let (i_min, i_max) = x.argminmax_by(f).unwrap();
+    /// 
+    /// assert_eq!(i_min, 7);
+    /// assert_eq!(i_max, 3);
+    /// ```
+    fn argminmax_by<'a, F>(&'a self, f: F) -> Option<(usize, usize)>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering + ~const Destruct,
+        T: 'a;
+    /// Finds the indices of both the minimum and the maximum key in the slice, in a single pass, given a hashing function.
+    /// 
+    /// If there are multiple minimums or maxima, only the first of each will have its index returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["1", "5", "5", "6", "2", "-1", "0", "-4", "-1", "6"];
+    /// 
+    /// let f = |&e| i32::from_str_radix(e, 10).unwrap();
+    /// 
+    /// let (i_min, i_max) = x.argminmax_by_key(f).unwrap();
+    /// 
+    /// assert_eq!(i_min, 7);
+    /// assert_eq!(i_max, 3);
+    /// ```
+    fn argminmax_by_key<'a, B, F>(&'a self, f: F) -> Option<(usize, usize)>
+    where
+        F: FnMut(&'a T) -> B + ~const Destruct,
+        B: PartialOrd,
+        T: 'a;
+    /// Finds the index of the maximum value in a slice of floating-point numbers, handling NaN as specified by `policy`.
+    /// 
+    /// Complex numbers are compared by their magnitude.
+    /// 
+    /// If there are multiple maxima, only the first will have its index returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [1.0, 5.0, f64::NAN, 6.0, 2.0, f64::NAN, -4.0];
+    /// 
+    /// assert_eq!(x.argmax_float(NanPolicy::Skip), Some(3));
+    /// assert_eq!(x.argmax_float(NanPolicy::Largest), Some(2));
+    /// assert_eq!(x.argmax_float(NanPolicy::First), Some(2));
+    /// 
+    /// let y = [f64::NAN, f64::NAN];
+    /// 
+    /// assert_eq!(y.argmax_float(NanPolicy::Skip), None);
+    /// ```
+    fn argmax_float(&self, policy: NanPolicy) -> Option<usize>
+    where
+        T: FloatKey;
+    /// Finds the index of the minimum value in a slice of floating-point numbers, handling NaN as specified by `policy`.
+    /// 
+    /// Complex numbers are compared by their magnitude.
+    /// 
+    /// If there are multiple minimums, only the first will have its index returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [1.0, 5.0, f64::NAN, 6.0, 2.0, f64::NAN, -4.0];
+    /// 
+    /// assert_eq!(x.argmin_float(NanPolicy::Skip), Some(6));
+    /// assert_eq!(x.argmin_float(NanPolicy::Largest), Some(6));
+    /// assert_eq!(x.argmin_float(NanPolicy::First), Some(2));
+    /// ```
+    fn argmin_float(&self, policy: NanPolicy) -> Option<usize>
+    where
+        T: FloatKey;
+    /// Finds the index of the maximum floating-point key in the slice, given a hashing function, handling NaN as specified by `policy`.
+    /// 
+    /// Complex keys are compared by their magnitude.
+    /// 
+    /// If there are multiple maxima, only the first will have its index returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["1", "5", "NaN", "6", "2", "NaN", "-4"];
+    /// 
+    /// let f = |&e: &&str| e.parse::<f64>().unwrap();
+    /// 
+    /// assert_eq!(x.argmax_by_key_float(f, NanPolicy::Skip), Some(3));
+    /// assert_eq!(x.argmax_by_key_float(f, NanPolicy::First), Some(2));
+    /// ```
+    fn argmax_by_key_float<'a, B, F>(&'a self, f: F, policy: NanPolicy) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> B + ~const Destruct,
+        B: FloatKey,
+        T: 'a;
+    /// Finds the index of the minimum floating-point key in the slice, given a hashing function, handling NaN as specified by `policy`.
+    /// 
+    /// Complex keys are compared by their magnitude.
+    /// 
+    /// If there are multiple minimums, only the first will have its index returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["1", "5", "NaN", "6", "2", "NaN", "-4"];
+    /// 
+    /// let f = |&e: &&str| e.parse::<f64>().unwrap();
+    /// 
+    /// assert_eq!(x.argmin_by_key_float(f, NanPolicy::Skip), Some(6));
+    /// assert_eq!(x.argmin_by_key_float(f, NanPolicy::First), Some(2));
+    /// ```
+    fn argmin_by_key_float<'a, B, F>(&'a self, f: F, policy: NanPolicy) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> B + ~const Destruct,
+        B: FloatKey,
+        T: 'a;
 }
 
 impl<T> SliceArgMinMax<T> for [T]
@@ -181,6 +347,111 @@ impl<T> SliceArgMinMax<T> for [T]
     {
         self.argreduce_key(PartialOrd::lt, f)
     }
+    fn argminmax(&self) -> Option<(usize, usize)>
+    where
+        T: PartialOrd
+    {
+        self.argreduce2(PartialOrd::lt, PartialOrd::gt)
+    }
+    fn argminmax_by<'a, F>(&'a self, f: F) -> Option<(usize, usize)>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering,
+        T: 'a
+    {
+        let f = RefCell::new(f);
+        self.argreduce2(
+            |a, b| matches!((f.borrow_mut())(a, b), Ordering::Less),
+            |a, b| matches!((f.borrow_mut())(a, b), Ordering::Greater)
+        )
+    }
+    fn argminmax_by_key<'a, B, F>(&'a self, f: F) -> Option<(usize, usize)>
+    where
+        F: FnMut(&'a T) -> B,
+        B: PartialOrd,
+        T: 'a
+    {
+        self.argreduce2_key(PartialOrd::lt, PartialOrd::gt, f)
+    }
+    fn argmax_float(&self, policy: NanPolicy) -> Option<usize>
+    where
+        T: FloatKey
+    {
+        argreduce_float(self, FloatKey::float_key, policy, Ordering::Greater)
+    }
+    fn argmin_float(&self, policy: NanPolicy) -> Option<usize>
+    where
+        T: FloatKey
+    {
+        argreduce_float(self, FloatKey::float_key, policy, Ordering::Less)
+    }
+    fn argmax_by_key_float<'a, B, F>(&'a self, mut f: F, policy: NanPolicy) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: FloatKey,
+        T: 'a
+    {
+        argreduce_float(self, |x| f(x).float_key(), policy, Ordering::Greater)
+    }
+    fn argmin_by_key_float<'a, B, F>(&'a self, mut f: F, policy: NanPolicy) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: FloatKey,
+        T: 'a
+    {
+        argreduce_float(self, |x| f(x).float_key(), policy, Ordering::Less)
+    }
+}
+
+/// Determines how NaN values are treated when finding extrema of floating-point numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NanPolicy
+{
+    /// NaN values are skipped. If all values are NaN, there is no result.
+    Skip,
+    /// NaN values are treated as larger than any other value.
+    Largest,
+    /// The index of the first NaN value is returned, if there is one.
+    First
+}
+
+impl NanPolicy
+{
+    /// Returns `true` if `next` should replace `prev` as the current extremum, where `order` is the ordering `next` must have relative to `prev`.
+    fn prefers<K>(self, next: &Option<K>, prev: &Option<K>, order: Ordering) -> bool
+    where
+        K: PartialOrd
+    {
+        match (next, prev)
+        {
+            (Some(next), Some(prev)) => next.partial_cmp(prev) == Some(order),
+            (Some(_), None) => match self
+            {
+                NanPolicy::Skip => true,
+                NanPolicy::Largest => order == Ordering::Less,
+                NanPolicy::First => false
+            },
+            (None, Some(_)) => match self
+            {
+                NanPolicy::Skip => false,
+                NanPolicy::Largest => order == Ordering::Greater,
+                NanPolicy::First => true
+            },
+            (None, None) => false
+        }
+    }
+}
+
+fn argreduce_float<'a, T, K, F>(slice: &'a [T], mut hasher: F, policy: NanPolicy, order: Ordering) -> Option<usize>
+where
+    F: FnMut(&'a T) -> Option<K>,
+    K: PartialOrd
+{
+    let j = slice.argreduce_key(|next, prev| policy.prefers(next, prev, order), &mut hasher)?;
+    if policy == NanPolicy::Skip && hasher(&slice[j]).is_none()
+    {
+        return None
+    }
+    Some(j)
 }
 
 #[cfg(test)]
@@ -199,4 +470,55 @@ mod test
 
         println!("{}", i);
     }
+
+    #[test]
+    fn argminmax()
+    {
+        let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3, 2, 1];
+
+        for n in 0..=x.len()
+        {
+            for m in 0..n
+            {
+                let slice = &x[m..n];
+                assert_eq!(slice.argminmax(), slice.argmin().zip(slice.argmax()));
+                assert_eq!(slice.argminmax_by(Ord::cmp), slice.argmin_by(Ord::cmp).zip(slice.argmax_by(Ord::cmp)));
+                assert_eq!(slice.argminmax_by_key(|&x| -x), slice.argmin_by_key(|&x| -x).zip(slice.argmax_by_key(|&x| -x)));
+            }
+        }
+
+        assert_eq!([0.0, 1.0, f64::NAN].argminmax(), Some((0, 1)));
+
+        // Incomparable pairs must not hide either element from the candidates.
+        let x = [2.0, 1.0, f64::NAN, 0.0, 3.0, f64::NAN, f64::NAN, 4.0, -1.0, 1.0, 1.0, f64::NAN];
+        for n in 0..=x.len()
+        {
+            for m in 0..n
+            {
+                let slice = &x[m..n];
+                assert_eq!(slice.argminmax(), slice.argmin().zip(slice.argmax()));
+                assert_eq!(slice.argminmax_by_key(|&x| -x), slice.argmin_by_key(|&x| -x).zip(slice.argmax_by_key(|&x| -x)));
+            }
+        }
+    }
+    #[test]
+    fn argmax_float()
+    {
+        use crate::ops::NanPolicy;
+
+        let x = [f64::NAN, 1.0, 5.0, f64::NAN, -4.0];
+
+        assert_eq!(x.argmax_float(NanPolicy::Skip), Some(2));
+        assert_eq!(x.argmin_float(NanPolicy::Skip), Some(4));
+        assert_eq!(x.argmax_float(NanPolicy::Largest), Some(0));
+        assert_eq!(x.argmin_float(NanPolicy::Largest), Some(4));
+        assert_eq!(x.argmax_float(NanPolicy::First), Some(0));
+        assert_eq!(x.argmin_float(NanPolicy::First), Some(0));
+
+        let empty: [f64; 0] = [];
+
+        assert_eq!(empty.argmax_float(NanPolicy::Skip), None);
+        assert_eq!([f64::NAN].argmin_float(NanPolicy::Skip), None);
+        assert_eq!([f64::NAN].argmin_float(NanPolicy::Largest), Some(0));
+    }
 }
\ No newline at end of file
@@ -66,6 +66,70 @@ pub trait SliceArgReduce<T>: Slice<Item = T>
         FR: FnMut(&B, &B) -> bool /*+ ~const Destruct*/,
         FB: FnMut(&'a T) -> B /*+ ~const Destruct*/,
         T: 'a;
+
+    /// Performs two argument reductions in a single pass, finding the final righthand operand for which each comparison yields true.
+    /// 
+    /// Elements are visited pairwise, and the two elements in each pair are compared against each other first, so that only one of them has to be compared
+    /// against each of the current candidates. A pair costs three comparisons if `reduction1` prefers its second element, and four if `reduction2` does.
+    /// A pair of equal or incomparable elements costs up to six, since both elements then have to be compared against both candidates.
+    /// This adds up to between `3n/2` and `3n` comparisons, compared to `2n` for two separate passes. Random data without ties averages about `7n/4`,
+    /// while data with many ties, like long constant runs, does worse than two separate passes.
+    /// 
+    /// For this to give the same result as two separate calls to [`argreduce`](SliceArgReduce::argreduce), the two reductions must be each other's converse,
+    /// like [`PartialOrd::lt`] and [`PartialOrd::gt`].
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// fn my_argminmax<T>(slice: &[T]) -> Option<(usize, usize)>
+    /// where
+    ///     T: PartialOrd
+    /// {
+    ///     slice.argreduce2(PartialOrd::lt, PartialOrd::gt)
+    /// }
+    /// 
+    /// let x = [1, 5, 5, 6, 2, -1, 0, -4, -1, 6];
+    /// 
+    /// assert_eq!(my_argminmax(&x), Some((7, 3)));
+    /// assert_eq!(my_argminmax(&x), x.argmin().zip(x.argmax()));
+    /// ```
+    fn argreduce2<'a, F1, F2>(&'a self, reduction1: F1, reduction2: F2) -> Option<(usize, usize)>
+    where
+        F1: FnMut(&'a T, &'a T) -> bool /*+ ~const Destruct*/,
+        F2: FnMut(&'a T, &'a T) -> bool /*+ ~const Destruct*/,
+        T: 'a;
+
+    /// Performs two argument reductions on the hashed values in a single pass, finding the final righthand operand for which each comparison yields true.
+    /// 
+    /// Like with [`argreduce2`](SliceArgReduce::argreduce2), the two reductions must be each other's converse.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// fn hasher(str: &&str) -> i32
+    /// {
+    ///     i32::from_str_radix(str, 10).unwrap()
+    /// }
+    /// 
+    /// fn my_argminmax(slice: &[&str]) -> Option<(usize, usize)>
+    /// {
+    ///     slice.argreduce2_key(PartialOrd::lt, PartialOrd::gt, hasher)
+    /// }
+    /// 
+    /// let x = ["1", "5", "5", "6", "2", "-1", "0", "-4", "-1", "6"];
+    /// 
+    /// assert_eq!(my_argminmax(&x), x.argmin_by_key(hasher).zip(x.argmax_by_key(hasher)));
+    /// ```
+    fn argreduce2_key<'a, B, FR1, FR2, FB>(&'a self, reduction1: FR1, reduction2: FR2, hasher: FB) -> Option<(usize, usize)>
+    where
+        FR1: FnMut(&B, &B) -> bool /*+ ~const Destruct*/,
+        FR2: FnMut(&B, &B) -> bool /*+ ~const Destruct*/,
+        FB: FnMut(&'a T) -> B /*+ ~const Destruct*/,
+        T: 'a;
 }
 
 impl<T> SliceArgReduce<T> for [T]
@@ -118,14 +182,190 @@ impl<T> SliceArgReduce<T> for [T]
         }
         Some(j)
     }
+    fn argreduce2<'a, F1, F2>(&'a self, mut f1: F1, mut f2: F2) -> Option<(usize, usize)>
+    where
+        F1: FnMut(&'a T, &'a T) -> bool,
+        F2: FnMut(&'a T, &'a T) -> bool,
+        T: 'a
+    {
+        let l = self.len();
+        if l == 0
+        {
+            return None;
+        }
+        let mut i = 1;
+        let mut j1 = 0;
+        let mut j2 = 0;
+        while i + 1 < l
+        {
+            let a = &self[i];
+            let b = &self[i + 1];
+            if f1(b, a)
+            {
+                if f1(b, &self[j1])
+                {
+                    j1 = i + 1;
+                }
+                if f2(a, &self[j2])
+                {
+                    j2 = i;
+                }
+            }
+            else if f2(b, a)
+            {
+                if f1(a, &self[j1])
+                {
+                    j1 = i;
+                }
+                if f2(b, &self[j2])
+                {
+                    j2 = i + 1;
+                }
+            }
+            else
+            {
+                // The pair is equal or incomparable, so both elements have to be checked against the candidates.
+                if f1(a, &self[j1])
+                {
+                    j1 = i;
+                }
+                else if f1(b, &self[j1])
+                {
+                    j1 = i + 1;
+                }
+                if f2(a, &self[j2])
+                {
+                    j2 = i;
+                }
+                else if f2(b, &self[j2])
+                {
+                    j2 = i + 1;
+                }
+            }
+            i += 2;
+        }
+        if i < l
+        {
+            if f1(&self[i], &self[j1])
+            {
+                j1 = i;
+            }
+            if f2(&self[i], &self[j2])
+            {
+                j2 = i;
+            }
+        }
+        Some((j1, j2))
+    }
+    fn argreduce2_key<'a, B, FR1, FR2, FB>(&'a self, mut predicate1: FR1, mut predicate2: FR2, mut hasher: FB) -> Option<(usize, usize)>
+    where
+        FR1: FnMut(&B, &B) -> bool,
+        FR2: FnMut(&B, &B) -> bool,
+        FB: FnMut(&'a T) -> B,
+        T: 'a
+    {
+        let l = self.len();
+        if l == 0
+        {
+            return None;
+        }
+        let mut i = 1;
+        let mut j1 = 0;
+        let mut j2 = 0;
+        // `key2` is `None` while both reductions share the key in `key1`, so that every element is hashed exactly once.
+        let mut key1 = hasher(&self[0]);
+        let mut key2 = None;
+        while i + 1 < l
+        {
+            let keys = [hasher(&self[i]), hasher(&self[i + 1])];
+            let best2 = key2.as_ref().unwrap_or(&key1);
+            let (k1, k2) = if predicate1(&keys[1], &keys[0])
+            {
+                (
+                    predicate1(&keys[1], &key1).then_some(1),
+                    predicate2(&keys[0], best2).then_some(0)
+                )
+            }
+            else if predicate2(&keys[1], &keys[0])
+            {
+                (
+                    predicate1(&keys[0], &key1).then_some(0),
+                    predicate2(&keys[1], best2).then_some(1)
+                )
+            }
+            else
+            {
+                // The pair is equal or incomparable, so both keys have to be checked against the candidates.
+                (
+                    if predicate1(&keys[0], &key1) {Some(0)} else {predicate1(&keys[1], &key1).then_some(1)},
+                    if predicate2(&keys[0], best2) {Some(0)} else {predicate2(&keys[1], best2).then_some(1)}
+                )
+            };
+            let mut keys = keys.map(Some);
+            if let Some(k2) = k2
+            {
+                j2 = i + k2;
+                key2 = if k1 == Some(k2) {None} else {keys[k2].take()};
+            }
+            if let Some(k1) = k1
+            {
+                j1 = i + k1;
+                let key = keys[k1].take().unwrap();
+                if key2.is_none() && k2 != Some(k1)
+                {
+                    key2 = Some(core::mem::replace(&mut key1, key));
+                }
+                else
+                {
+                    key1 = key;
+                }
+            }
+            i += 2;
+        }
+        if i < l
+        {
+            let key = hasher(&self[i]);
+            if predicate2(&key, key2.as_ref().unwrap_or(&key1))
+            {
+                j2 = i;
+            }
+            if predicate1(&key, &key1)
+            {
+                j1 = i;
+            }
+        }
+        Some((j1, j2))
+    }
 }
 
 #[cfg(test)]
 mod test
 {
+    use core::cell::Cell;
+
+    use crate::ops::{SliceArgMinMax, SliceArgReduce};
+
     #[test]
     fn it_works()
     {
         
     }
+
+    #[test]
+    fn argreduce2_key_hashes_once()
+    {
+        let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3, 2, 1];
+
+        for n in 0..=x.len()
+        {
+            let slice = &x[..n];
+            let calls = Cell::new(0);
+            let result = slice.argreduce2_key(PartialOrd::lt, PartialOrd::gt, |&x| {
+                calls.set(calls.get() + 1);
+                x % 4
+            });
+            assert_eq!(calls.get(), n);
+            assert_eq!(result, slice.argmin_by_key(|&x| x % 4).zip(slice.argmax_by_key(|&x| x % 4)));
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,460 @@
+use core::cmp::Ordering;
+
+use slice_trait::Slice;
+
+use super::SlicePermute;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[const_trait]
+pub trait SliceArgSort<T>: Slice<Item = T>
+{
+    /// Finds the permutation that sorts the slice, and writes it into `perm`, without moving the elements.
+    /// 
+    /// After this, `perm[i]` is the index of the element that would be at position `i` if the slice was sorted.
+    /// 
+    /// This sort is stable, meaning equal elements keep their relative order.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` does not have the same length as the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6];
+    /// 
+    /// let mut perm = [0; 8];
+    /// 
+    /// x.argsort(&mut perm);
+    /// 
+    /// assert_eq!(perm, [1, 3, 6, 0, 2, 4, 7, 5]);
+    /// ```
+    fn argsort(&self, perm: &mut [usize])
+    where
+        T: Ord;
+    /// Finds the permutation that sorts the slice, given a comparison predicate, and writes it into `perm`, without moving the elements.
+    /// 
+    /// This sort is stable, meaning equal elements keep their relative order.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` does not have the same length as the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6];
+    /// 
+    /// let mut perm = [0; 8];
+    /// 
+    /// x.argsort_by(&mut perm, |a, b| b.cmp(a));
+    /// 
+    /// assert_eq!(perm, [5, 7, 4, 2, 0, 6, 1, 3]);
+    /// ```
+    fn argsort_by<'a, F>(&'a self, perm: &mut [usize], compare: F)
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering /*+ ~const Destruct*/,
+        T: 'a;
+    /// Finds the permutation that sorts the slice, given a hashing function, and writes it into `perm`, without moving the elements.
+    /// 
+    /// This sort is stable, meaning elements with equal keys keep their relative order.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` does not have the same length as the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["3", "1", "4", "1", "5", "9", "2", "6"];
+    /// 
+    /// let mut perm = [0; 8];
+    /// 
+    /// x.argsort_by_key(&mut perm, |&e| i32::from_str_radix(e, 10).unwrap());
+    /// 
+    /// assert_eq!(perm, [1, 3, 6, 0, 2, 4, 7, 5]);
+    /// ```
+    fn argsort_by_key<'a, B, F>(&'a self, perm: &mut [usize], f: F)
+    where
+        F: FnMut(&'a T) -> B /*+ ~const Destruct*/,
+        B: Ord,
+        T: 'a;
+    /// Finds the permutation that sorts the slice, and writes it into `perm`, without moving the elements.
+    /// 
+    /// This sort is unstable, meaning equal elements may end up in any order.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` does not have the same length as the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 5, 9, 2, 6];
+    /// 
+    /// let mut perm = [0; 7];
+    /// 
+    /// x.argsort_unstable(&mut perm);
+    /// 
+    /// assert_eq!(perm, [1, 5, 0, 2, 3, 6, 4]);
+    /// ```
+    fn argsort_unstable(&self, perm: &mut [usize])
+    where
+        T: Ord;
+    /// Finds the permutation that sorts the slice, given a comparison predicate, and writes it into `perm`, without moving the elements.
+    /// 
+    /// This sort is unstable, meaning equal elements may end up in any order.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` does not have the same length as the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 5, 9, 2, 6];
+    /// 
+    /// let mut perm = [0; 7];
+    /// 
+    /// x.argsort_unstable_by(&mut perm, |a, b| b.cmp(a));
+    /// 
+    /// assert_eq!(perm, [4, 6, 3, 2, 0, 5, 1]);
+    /// ```
+    fn argsort_unstable_by<'a, F>(&'a self, perm: &mut [usize], compare: F)
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering /*+ ~const Destruct*/,
+        T: 'a;
+    /// Finds the permutation that sorts the slice, given a hashing function, and writes it into `perm`, without moving the elements.
+    /// 
+    /// This sort is unstable, meaning elements with equal keys may end up in any order.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` does not have the same length as the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["3", "1", "4", "5", "9", "2", "6"];
+    /// 
+    /// let mut perm = [0; 7];
+    /// 
+    /// x.argsort_unstable_by_key(&mut perm, |&e| i32::from_str_radix(e, 10).unwrap());
+    /// 
+    /// assert_eq!(perm, [1, 5, 0, 2, 3, 6, 4]);
+    /// ```
+    fn argsort_unstable_by_key<'a, B, F>(&'a self, perm: &mut [usize], f: F)
+    where
+        F: FnMut(&'a T) -> B /*+ ~const Destruct*/,
+        B: Ord,
+        T: 'a;
+        
+    /// Finds the permutation that sorts the slice, and returns it in a boxed slice.
+    /// 
+    /// This sort is stable, meaning equal elements keep their relative order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6];
+    /// 
+    /// let perm = x.argsort_boxed();
+    /// 
+    /// assert_eq!(*perm, [1, 3, 6, 0, 2, 4, 7, 5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argsort_boxed(&self) -> Box<[usize]>
+    where
+        T: Ord;
+    /// Finds the permutation that sorts the slice, given a comparison predicate, and returns it in a boxed slice.
+    /// 
+    /// This sort is stable, meaning equal elements keep their relative order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6];
+    /// 
+    /// let perm = x.argsort_boxed_by(|a, b| b.cmp(a));
+    /// 
+    /// assert_eq!(*perm, [5, 7, 4, 2, 0, 6, 1, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argsort_boxed_by<'a, F>(&'a self, compare: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering /*+ ~const Destruct*/,
+        T: 'a;
+    /// Finds the permutation that sorts the slice, given a hashing function, and returns it in a boxed slice.
+    /// 
+    /// This sort is stable, meaning elements with equal keys keep their relative order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["3", "1", "4", "1", "5", "9", "2", "6"];
+    /// 
+    /// let perm = x.argsort_boxed_by_key(|&e| i32::from_str_radix(e, 10).unwrap());
+    /// 
+    /// assert_eq!(*perm, [1, 3, 6, 0, 2, 4, 7, 5]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argsort_boxed_by_key<'a, B, F>(&'a self, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T) -> B /*+ ~const Destruct*/,
+        B: Ord,
+        T: 'a;
+    /// Finds the permutation that sorts the slice, and returns it in a boxed slice.
+    /// 
+    /// This sort is unstable, meaning equal elements may end up in any order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 5, 9, 2, 6];
+    /// 
+    /// let perm = x.argsort_unstable_boxed();
+    /// 
+    /// assert_eq!(*perm, [1, 5, 0, 2, 3, 6, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argsort_unstable_boxed(&self) -> Box<[usize]>
+    where
+        T: Ord;
+    /// Finds the permutation that sorts the slice, given a comparison predicate, and returns it in a boxed slice.
+    /// 
+    /// This sort is unstable, meaning equal elements may end up in any order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 5, 9, 2, 6];
+    /// 
+    /// let perm = x.argsort_unstable_boxed_by(|a, b| b.cmp(a));
+    /// 
+    /// assert_eq!(*perm, [4, 6, 3, 2, 0, 5, 1]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argsort_unstable_boxed_by<'a, F>(&'a self, compare: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering /*+ ~const Destruct*/,
+        T: 'a;
+    /// Finds the permutation that sorts the slice, given a hashing function, and returns it in a boxed slice.
+    /// 
+    /// This sort is unstable, meaning elements with equal keys may end up in any order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["3", "1", "4", "5", "9", "2", "6"];
+    /// 
+    /// let perm = x.argsort_unstable_boxed_by_key(|&e| i32::from_str_radix(e, 10).unwrap());
+    /// 
+    /// assert_eq!(*perm, [1, 5, 0, 2, 3, 6, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argsort_unstable_boxed_by_key<'a, B, F>(&'a self, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T) -> B /*+ ~const Destruct*/,
+        B: Ord,
+        T: 'a;
+
+    /// Reorders the slice by a permutation, as given by [`argsort`](SliceArgSort::argsort), so that element `perm[i]` ends up at position `i`.
+    /// 
+    /// The permutation buffer is used to keep track of which elements have been moved, but it is restored before returning.
+    /// This makes it possible to sort several parallel slices by the same permutation.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` does not have the same length as the slice, or if it's not a valid permutation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut keys = [3, 1, 4, 1, 5];
+    /// let mut names = ["c", "a", "d", "b", "e"];
+    /// 
+    /// let mut perm = [0; 5];
+    /// keys.argsort(&mut perm);
+    /// 
+    /// keys.apply_argsort(&mut perm);
+    /// names.apply_argsort(&mut perm);
+    /// 
+    /// assert_eq!(keys, [1, 1, 3, 4, 5]);
+    /// assert_eq!(names, ["a", "b", "c", "d", "e"]);
+    /// ```
+    fn apply_argsort(&mut self, perm: &mut [usize]);
+}
+
+impl<T> SliceArgSort<T> for [T]
+{
+    fn argsort(&self, perm: &mut [usize])
+    where
+        T: Ord
+    {
+        self.argsort_by(perm, Ord::cmp)
+    }
+    fn argsort_by<'a, F>(&'a self, perm: &mut [usize], mut compare: F)
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering,
+        T: 'a
+    {
+        init_perm(perm, self.len());
+        // Breaking ties by index makes the unstable sort stable, without needing a buffer.
+        perm.sort_unstable_by(|&i, &j| compare(&self[i], &self[j]).then(i.cmp(&j)))
+    }
+    fn argsort_by_key<'a, B, F>(&'a self, perm: &mut [usize], mut f: F)
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+        T: 'a
+    {
+        self.argsort_by(perm, |a, b| f(a).cmp(&f(b)))
+    }
+    fn argsort_unstable(&self, perm: &mut [usize])
+    where
+        T: Ord
+    {
+        self.argsort_unstable_by(perm, Ord::cmp)
+    }
+    fn argsort_unstable_by<'a, F>(&'a self, perm: &mut [usize], mut compare: F)
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering,
+        T: 'a
+    {
+        init_perm(perm, self.len());
+        perm.sort_unstable_by(|&i, &j| compare(&self[i], &self[j]))
+    }
+    fn argsort_unstable_by_key<'a, B, F>(&'a self, perm: &mut [usize], mut f: F)
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+        T: 'a
+    {
+        self.argsort_unstable_by(perm, |a, b| f(a).cmp(&f(b)))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn argsort_boxed(&self) -> Box<[usize]>
+    where
+        T: Ord
+    {
+        let mut perm = alloc::vec![0; self.len()].into_boxed_slice();
+        self.argsort(&mut perm);
+        perm
+    }
+    #[cfg(feature = "alloc")]
+    fn argsort_boxed_by<'a, F>(&'a self, compare: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering,
+        T: 'a
+    {
+        let mut perm = alloc::vec![0; self.len()].into_boxed_slice();
+        self.argsort_by(&mut perm, compare);
+        perm
+    }
+    #[cfg(feature = "alloc")]
+    fn argsort_boxed_by_key<'a, B, F>(&'a self, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+        T: 'a
+    {
+        let mut perm = alloc::vec![0; self.len()].into_boxed_slice();
+        self.argsort_by_key(&mut perm, f);
+        perm
+    }
+    #[cfg(feature = "alloc")]
+    fn argsort_unstable_boxed(&self) -> Box<[usize]>
+    where
+        T: Ord
+    {
+        let mut perm = alloc::vec![0; self.len()].into_boxed_slice();
+        self.argsort_unstable(&mut perm);
+        perm
+    }
+    #[cfg(feature = "alloc")]
+    fn argsort_unstable_boxed_by<'a, F>(&'a self, compare: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering,
+        T: 'a
+    {
+        let mut perm = alloc::vec![0; self.len()].into_boxed_slice();
+        self.argsort_unstable_by(&mut perm, compare);
+        perm
+    }
+    #[cfg(feature = "alloc")]
+    fn argsort_unstable_boxed_by_key<'a, B, F>(&'a self, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T) -> B,
+        B: Ord,
+        T: 'a
+    {
+        let mut perm = alloc::vec![0; self.len()].into_boxed_slice();
+        self.argsort_unstable_by_key(&mut perm, f);
+        perm
+    }
+
+    fn apply_argsort(&mut self, perm: &mut [usize])
+    {
+        self.apply_permutation(perm)
+    }
+}
+
+fn init_perm(perm: &mut [usize], len: usize)
+{
+    assert_eq!(perm.len(), len, "Permutation must have the same length as the slice.");
+    super::identity_permutation(perm)
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::SliceArgSort;
+
+    #[test]
+    fn it_works()
+    {
+        let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3, 2, 3, 8, 4];
+
+        let mut perm = [0; 20];
+        x.argsort(&mut perm);
+
+        let mut sorted = x;
+        let mut y: [usize; 20] = core::array::from_fn(|i| i);
+        sorted.apply_argsort(&mut perm);
+        y.apply_argsort(&mut perm);
+
+        let mut expected = x;
+        expected.sort();
+        assert_eq!(sorted, expected);
+        assert_eq!(y, perm);
+    }
+}
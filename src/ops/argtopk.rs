@@ -0,0 +1,522 @@
+use core::cmp::Ordering;
+
+use slice_trait::Slice;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[const_trait]
+pub trait SliceArgTopK<T>: Slice<Item = T>
+{
+    /// Finds the indices of the `k` largest values in the slice, and writes them into `out`.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from largest to smallest value, otherwise they are in no particular order.
+    /// Equal values are ranked by their index, so that the first ones are selected.
+    /// 
+    /// Runs in `O(n log k)` time, without allocating.
+    /// 
+    /// Returns the number of indices written, which is `k` or the length of the slice, whichever is smaller.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `out` is too short to hold all the indices.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    /// 
+    /// let mut i = [0; 3];
+    /// 
+    /// let n = x.argtopk(3, &mut i, true);
+    /// 
+    /// assert_eq!(n, 3);
+    /// assert_eq!(i, [5, 7, 4]);
+    /// ```
+    fn argtopk(&self, k: usize, out: &mut [usize], sorted: bool) -> usize
+    where
+        T: PartialOrd<T>;
+    /// Finds the indices of the `k` smallest values in the slice, and writes them into `out`.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from smallest to largest value, otherwise they are in no particular order.
+    /// Equal values are ranked by their index, so that the first ones are selected.
+    /// 
+    /// Runs in `O(n log k)` time, without allocating.
+    /// 
+    /// Returns the number of indices written, which is `k` or the length of the slice, whichever is smaller.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `out` is too short to hold all the indices.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    /// 
+    /// let mut i = [0; 3];
+    /// 
+    /// let n = x.argbottomk(3, &mut i, true);
+    /// 
+    /// assert_eq!(n, 3);
+    /// assert_eq!(i, [1, 3, 6]);
+    /// ```
+    fn argbottomk(&self, k: usize, out: &mut [usize], sorted: bool) -> usize
+    where
+        T: PartialOrd<T>;
+    /// Finds the indices of the `k` largest values in the slice, given a comparison predicate, and writes them into `out`.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from largest to smallest value, otherwise they are in no particular order.
+    /// 
+    /// Returns the number of indices written.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    /// 
+    /// let mut i = [0; 3];
+    /// 
+    /// x.argtopk_by(3, &mut i, true, Ord::cmp);
+    /// 
+    /// assert_eq!(i, [5, 7, 4]);
+    /// ```
+    fn argtopk_by<'a, F>(&'a self, k: usize, out: &mut [usize], sorted: bool, f: F) -> usize
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering /*+ ~const Destruct*/,
+        T: 'a;
+    /// Finds the indices of the `k` smallest values in the slice, given a comparison predicate, and writes them into `out`.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from smallest to largest value, otherwise they are in no particular order.
+    /// 
+    /// Returns the number of indices written.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    /// 
+    /// let mut i = [0; 3];
+    /// 
+    /// x.argbottomk_by(3, &mut i, true, Ord::cmp);
+    /// 
+    /// assert_eq!(i, [1, 3, 6]);
+    /// ```
+    fn argbottomk_by<'a, F>(&'a self, k: usize, out: &mut [usize], sorted: bool, f: F) -> usize
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering /*+ ~const Destruct*/,
+        T: 'a;
+    /// Finds the indices of the `k` largest keys in the slice, given a hashing function, and writes them into `out`.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from largest to smallest key, otherwise they are in no particular order.
+    /// 
+    /// The hashing function may be called several times for each element.
+    /// 
+    /// Returns the number of indices written.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["3", "1", "4", "1", "5", "9", "2", "6", "5", "3"];
+    /// 
+    /// let f = |&e| i32::from_str_radix(e, 10).unwrap();
+    /// 
+    /// let mut i = [0; 3];
+    /// 
+    /// x.argtopk_by_key(3, &mut i, true, f);
+    /// 
+    /// assert_eq!(i, [5, 7, 4]);
+    /// ```
+    fn argtopk_by_key<'a, B, F>(&'a self, k: usize, out: &mut [usize], sorted: bool, f: F) -> usize
+    where
+        F: FnMut(&'a T) -> B /*+ ~const Destruct*/,
+        B: PartialOrd,
+        T: 'a;
+    /// Finds the indices of the `k` smallest keys in the slice, given a hashing function, and writes them into `out`.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from smallest to largest key, otherwise they are in no particular order.
+    /// 
+    /// The hashing function may be called several times for each element.
+    /// 
+    /// Returns the number of indices written.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["3", "1", "4", "1", "5", "9", "2", "6", "5", "3"];
+    /// 
+    /// let f = |&e| i32::from_str_radix(e, 10).unwrap();
+    /// 
+    /// let mut i = [0; 3];
+    /// 
+    /// x.argbottomk_by_key(3, &mut i, true, f);
+    /// 
+    /// assert_eq!(i, [1, 3, 6]);
+    /// ```
+    fn argbottomk_by_key<'a, B, F>(&'a self, k: usize, out: &mut [usize], sorted: bool, f: F) -> usize
+    where
+        F: FnMut(&'a T) -> B /*+ ~const Destruct*/,
+        B: PartialOrd,
+        T: 'a;
+
+    /// Finds the indices of the `k` largest values in the slice, and returns them in a boxed slice.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from largest to smallest value, otherwise they are in no particular order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    /// 
+    /// let i = x.argtopk_boxed(3, true);
+    /// 
+    /// assert_eq!(*i, [5, 7, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argtopk_boxed(&self, k: usize, sorted: bool) -> Box<[usize]>
+    where
+        T: PartialOrd<T>;
+    /// Finds the indices of the `k` smallest values in the slice, and returns them in a boxed slice.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from smallest to largest value, otherwise they are in no particular order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    /// 
+    /// let i = x.argbottomk_boxed(3, true);
+    /// 
+    /// assert_eq!(*i, [1, 3, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argbottomk_boxed(&self, k: usize, sorted: bool) -> Box<[usize]>
+    where
+        T: PartialOrd<T>;
+    /// Finds the indices of the `k` largest values in the slice, given a comparison predicate, and returns them in a boxed slice.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from largest to smallest value, otherwise they are in no particular order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    /// 
+    /// let i = x.argtopk_boxed_by(3, true, Ord::cmp);
+    /// 
+    /// assert_eq!(*i, [5, 7, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argtopk_boxed_by<'a, F>(&'a self, k: usize, sorted: bool, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering /*+ ~const Destruct*/,
+        T: 'a;
+    /// Finds the indices of the `k` smallest values in the slice, given a comparison predicate, and returns them in a boxed slice.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from smallest to largest value, otherwise they are in no particular order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+    /// 
+    /// let i = x.argbottomk_boxed_by(3, true, Ord::cmp);
+    /// 
+    /// assert_eq!(*i, [1, 3, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argbottomk_boxed_by<'a, F>(&'a self, k: usize, sorted: bool, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering /*+ ~const Destruct*/,
+        T: 'a;
+    /// Finds the indices of the `k` largest keys in the slice, given a hashing function, and returns them in a boxed slice.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from largest to smallest key, otherwise they are in no particular order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["3", "1", "4", "1", "5", "9", "2", "6", "5", "3"];
+    /// 
+    /// let f = |&e| i32::from_str_radix(e, 10).unwrap();
+    /// 
+    /// let i = x.argtopk_boxed_by_key(3, true, f);
+    /// 
+    /// assert_eq!(*i, [5, 7, 4]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argtopk_boxed_by_key<'a, B, F>(&'a self, k: usize, sorted: bool, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T) -> B /*+ ~const Destruct*/,
+        B: PartialOrd,
+        T: 'a;
+    /// Finds the indices of the `k` smallest keys in the slice, given a hashing function, and returns them in a boxed slice.
+    /// 
+    /// If `sorted` is `true`, the indices are ordered from smallest to largest key, otherwise they are in no particular order.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ["3", "1", "4", "1", "5", "9", "2", "6", "5", "3"];
+    /// 
+    /// let f = |&e| i32::from_str_radix(e, 10).unwrap();
+    /// 
+    /// let i = x.argbottomk_boxed_by_key(3, true, f);
+    /// 
+    /// assert_eq!(*i, [1, 3, 6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn argbottomk_boxed_by_key<'a, B, F>(&'a self, k: usize, sorted: bool, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T) -> B /*+ ~const Destruct*/,
+        B: PartialOrd,
+        T: 'a;
+}
+
+impl<T> SliceArgTopK<T> for [T]
+{
+    fn argtopk(&self, k: usize, out: &mut [usize], sorted: bool) -> usize
+    where
+        T: PartialOrd
+    {
+        self.argtopk_by(k, out, sorted, |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+    }
+    fn argbottomk(&self, k: usize, out: &mut [usize], sorted: bool) -> usize
+    where
+        T: PartialOrd
+    {
+        self.argbottomk_by(k, out, sorted, |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+    }
+    fn argtopk_by<'a, F>(&'a self, k: usize, out: &mut [usize], sorted: bool, mut f: F) -> usize
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering,
+        T: 'a
+    {
+        argselect(self.len(), k, out, sorted, |i, j| ranks_before(f(&self[i], &self[j]), Ordering::Greater, i, j))
+    }
+    fn argbottomk_by<'a, F>(&'a self, k: usize, out: &mut [usize], sorted: bool, mut f: F) -> usize
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering,
+        T: 'a
+    {
+        argselect(self.len(), k, out, sorted, |i, j| ranks_before(f(&self[i], &self[j]), Ordering::Less, i, j))
+    }
+    fn argtopk_by_key<'a, B, F>(&'a self, k: usize, out: &mut [usize], sorted: bool, mut f: F) -> usize
+    where
+        F: FnMut(&'a T) -> B,
+        B: PartialOrd,
+        T: 'a
+    {
+        self.argtopk_by(k, out, sorted, |a, b| f(a).partial_cmp(&f(b)).unwrap_or(Ordering::Equal))
+    }
+    fn argbottomk_by_key<'a, B, F>(&'a self, k: usize, out: &mut [usize], sorted: bool, mut f: F) -> usize
+    where
+        F: FnMut(&'a T) -> B,
+        B: PartialOrd,
+        T: 'a
+    {
+        self.argbottomk_by(k, out, sorted, |a, b| f(a).partial_cmp(&f(b)).unwrap_or(Ordering::Equal))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn argtopk_boxed(&self, k: usize, sorted: bool) -> Box<[usize]>
+    where
+        T: PartialOrd
+    {
+        let mut out = alloc::vec![0; k.min(self.len())].into_boxed_slice();
+        self.argtopk(k, &mut out, sorted);
+        out
+    }
+    #[cfg(feature = "alloc")]
+    fn argbottomk_boxed(&self, k: usize, sorted: bool) -> Box<[usize]>
+    where
+        T: PartialOrd
+    {
+        let mut out = alloc::vec![0; k.min(self.len())].into_boxed_slice();
+        self.argbottomk(k, &mut out, sorted);
+        out
+    }
+    #[cfg(feature = "alloc")]
+    fn argtopk_boxed_by<'a, F>(&'a self, k: usize, sorted: bool, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering,
+        T: 'a
+    {
+        let mut out = alloc::vec![0; k.min(self.len())].into_boxed_slice();
+        self.argtopk_by(k, &mut out, sorted, f);
+        out
+    }
+    #[cfg(feature = "alloc")]
+    fn argbottomk_boxed_by<'a, F>(&'a self, k: usize, sorted: bool, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering,
+        T: 'a
+    {
+        let mut out = alloc::vec![0; k.min(self.len())].into_boxed_slice();
+        self.argbottomk_by(k, &mut out, sorted, f);
+        out
+    }
+    #[cfg(feature = "alloc")]
+    fn argtopk_boxed_by_key<'a, B, F>(&'a self, k: usize, sorted: bool, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T) -> B,
+        B: PartialOrd,
+        T: 'a
+    {
+        let mut out = alloc::vec![0; k.min(self.len())].into_boxed_slice();
+        self.argtopk_by_key(k, &mut out, sorted, f);
+        out
+    }
+    #[cfg(feature = "alloc")]
+    fn argbottomk_boxed_by_key<'a, B, F>(&'a self, k: usize, sorted: bool, f: F) -> Box<[usize]>
+    where
+        F: FnMut(&'a T) -> B,
+        B: PartialOrd,
+        T: 'a
+    {
+        let mut out = alloc::vec![0; k.min(self.len())].into_boxed_slice();
+        self.argbottomk_by_key(k, &mut out, sorted, f);
+        out
+    }
+}
+
+/// Returns `true` if element `i` ranks before element `j`, given their ordering. Ties are broken by index.
+fn ranks_before(ordering: Ordering, first: Ordering, i: usize, j: usize) -> bool
+{
+    if ordering == first
+    {
+        return true
+    }
+    ordering == Ordering::Equal && i < j
+}
+
+/// Selects the `k` best indices below `len` into `out`, using a binary heap with the worst selected index at its root.
+fn argselect<F>(len: usize, k: usize, out: &mut [usize], sorted: bool, mut before: F) -> usize
+where
+    F: FnMut(usize, usize) -> bool
+{
+    let m = k.min(len);
+    assert!(out.len() >= m, "Output buffer is too short.");
+    if m == 0
+    {
+        return 0
+    }
+    let heap = &mut out[..m];
+
+    let mut i = 0;
+    while i < m
+    {
+        heap[i] = i;
+        let mut j = i;
+        while j > 0
+        {
+            let p = (j - 1)/2;
+            if !before(heap[p], heap[j])
+            {
+                break
+            }
+            heap.swap(p, j);
+            j = p;
+        }
+        i += 1;
+    }
+    while i < len
+    {
+        if before(i, heap[0])
+        {
+            heap[0] = i;
+            sift_down(heap, 0, m, &mut before);
+        }
+        i += 1;
+    }
+
+    if sorted
+    {
+        let mut end = m;
+        while end > 1
+        {
+            end -= 1;
+            heap.swap(0, end);
+            sift_down(heap, 0, end, &mut before);
+        }
+    }
+
+    m
+}
+
+fn sift_down<F>(heap: &mut [usize], mut i: usize, len: usize, before: &mut F)
+where
+    F: FnMut(usize, usize) -> bool
+{
+    loop
+    {
+        let left = 2*i + 1;
+        if left >= len
+        {
+            break
+        }
+        let right = left + 1;
+        let worst = if right < len && before(heap[left], heap[right]) {right} else {left};
+        if !before(heap[i], heap[worst])
+        {
+            break
+        }
+        heap.swap(i, worst);
+        i = worst;
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::SliceArgTopK;
+
+    #[test]
+    fn argtopk()
+    {
+        let x = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9, 3, 2, 3, 8, 4];
+
+        let mut order: Vec<usize> = (0..x.len()).collect();
+        order.sort_by(|&i, &j| x[j].cmp(&x[i]));
+        let mut reverse_order: Vec<usize> = (0..x.len()).collect();
+        reverse_order.sort_by(|&i, &j| x[i].cmp(&x[j]));
+
+        for k in 0..=x.len() + 1
+        {
+            let mut top = [0; 20];
+            let n = x.argtopk(k, &mut top, true);
+            assert_eq!(n, k.min(x.len()));
+            assert_eq!(top[..n], order[..n]);
+
+            let mut bottom = [0; 20];
+            let n = x.argbottomk(k, &mut bottom, false);
+            let mut bottom = bottom[..n].to_vec();
+            bottom.sort_by(|&i, &j| x[i].cmp(&x[j]).then(i.cmp(&j)));
+            assert_eq!(bottom, reverse_order[..n]);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use core::slice::{ChunkBy, ChunkByMut, Split, SplitMut, SplitN, SplitNMut};
+
+use slice_trait::Slice;
+
+#[const_trait]
+pub trait SliceGroup<T>: Slice<Item = T>
+{
+    /// Returns an iterator over the maximal runs of the slice where each pair of consecutive elements satisfies the given relation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [1, 2, 3, 2, 3, 4, 0];
+    /// 
+    /// let mut groups = x.group_by(|a, b| a < b);
+    /// 
+    /// assert_eq!(groups.next(), Some(&[1, 2, 3][..]));
+    /// assert_eq!(groups.next(), Some(&[2, 3, 4][..]));
+    /// assert_eq!(groups.next(), Some(&[0][..]));
+    /// assert_eq!(groups.next(), None);
+    /// ```
+    fn group_by<F>(&self, f: F) -> ChunkBy<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool /*+ ~const Destruct*/;
+    /// Returns an iterator over the maximal mutable runs of the slice where each pair of consecutive elements satisfies the given relation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut x = [1, 2, 3, 2, 3, 4, 0];
+    /// 
+    /// for group in x.group_by_mut(|a, b| a < b)
+    /// {
+    ///     group.reverse();
+    /// }
+    /// 
+    /// assert_eq!(x, [3, 2, 1, 4, 3, 2, 0]);
+    /// ```
+    fn group_by_mut<F>(&mut self, f: F) -> ChunkByMut<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool /*+ ~const Destruct*/;
+
+    /// Returns an iterator over the maximal runs of equal elements.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [1, 1, 2, 3, 3, 3];
+    /// 
+    /// let lengths: Vec<usize> = x.runs().map(|run| run.len()).collect();
+    /// 
+    /// assert_eq!(lengths, [2, 1, 3]);
+    /// ```
+    fn runs(&self) -> ChunkBy<'_, T, fn(&T, &T) -> bool>
+    where
+        T: PartialEq;
+    /// Returns an iterator over the maximal mutable runs of equal elements.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut x = [1, 1, 2, 3, 3, 3];
+    /// 
+    /// for run in x.runs_mut()
+    /// {
+    ///     let n = run.len();
+    ///     run.fill(n);
+    /// }
+    /// 
+    /// assert_eq!(x, [2, 2, 1, 3, 3, 3]);
+    /// ```
+    fn runs_mut(&mut self) -> ChunkByMut<'_, T, fn(&T, &T) -> bool>
+    where
+        T: PartialEq;
+
+    /// Returns an iterator over the subslices separated by elements that satisfy the given predicate.
+    /// 
+    /// The separators are not included in the subslices.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [1, 2, 0, 3, 0, 0, 4];
+    /// 
+    /// let mut parts = x.split_on(|&e| e == 0);
+    /// 
+    /// assert_eq!(parts.next(), Some(&[1, 2][..]));
+    /// assert_eq!(parts.next(), Some(&[3][..]));
+    /// assert_eq!(parts.next(), Some(&[][..]));
+    /// assert_eq!(parts.next(), Some(&[4][..]));
+    /// assert_eq!(parts.next(), None);
+    /// ```
+    fn split_on<F>(&self, pred: F) -> Split<'_, T, F>
+    where
+        F: FnMut(&T) -> bool /*+ ~const Destruct*/;
+    /// Returns an iterator over the mutable subslices separated by elements that satisfy the given predicate.
+    /// 
+    /// The separators are not included in the subslices.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut x = [1, 2, 0, 3, 4];
+    /// 
+    /// for part in x.split_on_mut(|&e| e == 0)
+    /// {
+    ///     part.reverse();
+    /// }
+    /// 
+    /// assert_eq!(x, [2, 1, 0, 4, 3]);
+    /// ```
+    fn split_on_mut<F>(&mut self, pred: F) -> SplitMut<'_, T, F>
+    where
+        F: FnMut(&T) -> bool /*+ ~const Destruct*/;
+    /// Returns an iterator over at most `n` subslices separated by elements that satisfy the given predicate.
+    /// 
+    /// The last subslice contains the remainder of the slice, including any further separators.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [1, 0, 2, 0, 3];
+    /// 
+    /// let mut parts = x.splitn_on(2, |&e| e == 0);
+    /// 
+    /// assert_eq!(parts.next(), Some(&[1][..]));
+    /// assert_eq!(parts.next(), Some(&[2, 0, 3][..]));
+    /// assert_eq!(parts.next(), None);
+    /// ```
+    fn splitn_on<F>(&self, n: usize, pred: F) -> SplitN<'_, T, F>
+    where
+        F: FnMut(&T) -> bool /*+ ~const Destruct*/;
+    /// Returns an iterator over at most `n` mutable subslices separated by elements that satisfy the given predicate.
+    /// 
+    /// The last subslice contains the remainder of the slice, including any further separators.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut x = [1, 0, 2, 0, 3];
+    /// 
+    /// for part in x.splitn_on_mut(2, |&e| e == 0)
+    /// {
+    ///     part.fill(9);
+    /// }
+    /// 
+    /// assert_eq!(x, [9, 0, 9, 9, 9]);
+    /// ```
+    fn splitn_on_mut<F>(&mut self, n: usize, pred: F) -> SplitNMut<'_, T, F>
+    where
+        F: FnMut(&T) -> bool /*+ ~const Destruct*/;
+}
+
+impl<T> SliceGroup<T> for [T]
+{
+    fn group_by<F>(&self, f: F) -> ChunkBy<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool
+    {
+        self.chunk_by(f)
+    }
+    fn group_by_mut<F>(&mut self, f: F) -> ChunkByMut<'_, T, F>
+    where
+        F: FnMut(&T, &T) -> bool
+    {
+        self.chunk_by_mut(f)
+    }
+
+    fn runs(&self) -> ChunkBy<'_, T, fn(&T, &T) -> bool>
+    where
+        T: PartialEq
+    {
+        self.chunk_by(T::eq as fn(&T, &T) -> bool)
+    }
+    fn runs_mut(&mut self) -> ChunkByMut<'_, T, fn(&T, &T) -> bool>
+    where
+        T: PartialEq
+    {
+        self.chunk_by_mut(T::eq as fn(&T, &T) -> bool)
+    }
+
+    fn split_on<F>(&self, pred: F) -> Split<'_, T, F>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.split(pred)
+    }
+    fn split_on_mut<F>(&mut self, pred: F) -> SplitMut<'_, T, F>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.split_mut(pred)
+    }
+    fn splitn_on<F>(&self, n: usize, pred: F) -> SplitN<'_, T, F>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.splitn(n, pred)
+    }
+    fn splitn_on_mut<F>(&mut self, n: usize, pred: F) -> SplitNMut<'_, T, F>
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.splitn_mut(n, pred)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::SliceGroup;
+
+    #[test]
+    fn it_works()
+    {
+        // Segment a signal into strictly rising runs.
+        let x = [0.0, 0.5, 1.0, 0.7, 0.2, 0.4];
+
+        let edges: Vec<_> = x.group_by(|a, b| a < b).map(|run| run.len()).collect();
+        assert_eq!(edges, [3, 1, 2]);
+
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.runs().count(), 0);
+        assert_eq!(empty.split_on(|_| true).count(), 1);
+        assert_eq!([0, 0].split_on(|&e| e == 0).count(), 3);
+    }
+}
@@ -0,0 +1,241 @@
+use slice_trait::Slice;
+
+#[const_trait]
+pub trait SliceInterleave<T>: Slice<Item = T>
+{
+    /// Reorders an interleaved slice with `m` lanes into a planar layout, in-place.
+    /// 
+    /// Afterwards, the slice consists of each lane in order, where lane `i` contains the elements `i`, `i + m`, `i + 2m`, ... of the original slice.
+    /// If the slice's length is not divisible by `m`, the first lanes will be one element longer, just like with [`spread`](crate::ops::SliceSpread::spread).
+    /// 
+    /// This is done using rotations, with `O(m n log n)` time complexity and no extra space.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `m` is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut x = ['L', 'R', 'L', 'R', 'L', 'R'];
+    /// 
+    /// x.deinterleave(2);
+    /// 
+    /// assert_eq!(x, ['L', 'L', 'L', 'R', 'R', 'R']);
+    /// 
+    /// let mut y = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// 
+    /// y.deinterleave(3);
+    /// 
+    /// assert_eq!(y, [1, 4, 7, 2, 5, 8, 3, 6]);
+    /// ```
+    fn deinterleave(&mut self, m: usize);
+
+    /// Reorders a planar slice with `m` lanes into an interleaved layout, in-place.
+    /// 
+    /// This is the inverse of [`deinterleave`](SliceInterleave::deinterleave).
+    /// 
+    /// This is done using rotations, with `O(m n log n)` time complexity and no extra space.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `m` is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut x = ['L', 'L', 'L', 'R', 'R', 'R'];
+    /// 
+    /// x.interleave(2);
+    /// 
+    /// assert_eq!(x, ['L', 'R', 'L', 'R', 'L', 'R']);
+    /// 
+    /// let mut y = [1, 4, 7, 2, 5, 8, 3, 6];
+    /// 
+    /// y.interleave(3);
+    /// 
+    /// assert_eq!(y, [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    fn interleave(&mut self, m: usize);
+
+    /// Performs a perfect out-shuffle, in-place.
+    /// 
+    /// The two halves of the slice are interleaved, such that the first element stays on top.
+    /// If the length is odd, the first half is the longest.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut x = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// 
+    /// x.faro_out_shuffle();
+    /// 
+    /// assert_eq!(x, [1, 5, 2, 6, 3, 7, 4, 8]);
+    /// ```
+    fn faro_out_shuffle(&mut self);
+
+    /// Performs a perfect in-shuffle, in-place.
+    /// 
+    /// The two halves of the slice are interleaved, such that the first element of the second half ends up on top.
+    /// If the length is odd, the second half is the longest.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut x = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// 
+    /// x.faro_in_shuffle();
+    /// 
+    /// assert_eq!(x, [5, 1, 6, 2, 7, 3, 8, 4]);
+    /// ```
+    fn faro_in_shuffle(&mut self);
+}
+
+impl<T> SliceInterleave<T> for [T]
+{
+    fn deinterleave(&mut self, m: usize)
+    {
+        assert!(m > 0, "Number of lanes must be positive.");
+
+        // Merges consecutive groups of rows into planar layout, doubling the group size each pass.
+        let rows = self.len().div_ceil(m);
+        let mut w = 1;
+        while w < rows
+        {
+            let mut r0 = 0;
+            while r0 + w < rows
+            {
+                let r1 = r0 + w;
+                let r2 = (r1 + w).min(rows);
+                merge_lanes(self, m, r0, r1, r2);
+                r0 = r2;
+            }
+            w *= 2;
+        }
+    }
+
+    fn interleave(&mut self, m: usize)
+    {
+        assert!(m > 0, "Number of lanes must be positive.");
+
+        // Undoes the passes of `deinterleave` in reverse order.
+        let rows = self.len().div_ceil(m);
+        if rows <= 1
+        {
+            return
+        }
+        let mut w = 1;
+        while w*2 < rows
+        {
+            w *= 2;
+        }
+        loop
+        {
+            let mut r0 = 0;
+            while r0 + w < rows
+            {
+                let r1 = r0 + w;
+                let r2 = (r1 + w).min(rows);
+                split_lanes(self, m, r0, r1, r2);
+                r0 = r2;
+            }
+            if w == 1
+            {
+                break
+            }
+            w /= 2;
+        }
+    }
+
+    fn faro_out_shuffle(&mut self)
+    {
+        self.interleave(2)
+    }
+
+    fn faro_in_shuffle(&mut self)
+    {
+        let len = self.len();
+        self.rotate_left(len/2);
+        self.interleave(2)
+    }
+}
+
+/// Returns the number of elements of lane `l` within the rows `r0..r1`, for a slice of length `len` with `m` lanes.
+fn lane_len(len: usize, m: usize, r0: usize, r1: usize, l: usize) -> usize
+{
+    let rows = len.saturating_sub(l).div_ceil(m);
+    rows.min(r1).saturating_sub(r0)
+}
+
+/// Merges two consecutive planar groups of rows, `r0..r1` and `r1..r2`, into one planar group.
+fn merge_lanes<T>(slice: &mut [T], m: usize, r0: usize, r1: usize, r2: usize)
+{
+    let len = slice.len();
+    let mut start = r0*m;
+    let mut x_rest = (r1*m).min(len) - r0*m;
+    let mut l = 0;
+    while l + 1 < m
+    {
+        let x = lane_len(len, m, r0, r1, l);
+        let y = lane_len(len, m, r1, r2, l);
+        x_rest -= x;
+        slice[start + x..start + x + x_rest + y].rotate_right(y);
+        start += x + y;
+        l += 1;
+    }
+}
+
+/// Splits a planar group of rows `r0..r2` into two consecutive planar groups, `r0..r1` and `r1..r2`.
+fn split_lanes<T>(slice: &mut [T], m: usize, r0: usize, r1: usize, r2: usize)
+{
+    let len = slice.len();
+    let mut end = (r2*m).min(len);
+    let mut x_rest = 0;
+    let mut l = m;
+    while l > 1
+    {
+        l -= 1;
+        let x = lane_len(len, m, r0, r1, l);
+        end -= x + lane_len(len, m, r1, r2, l);
+        x_rest += x;
+        let y = lane_len(len, m, r1, r2, l - 1);
+        slice[end - y..end + x_rest].rotate_left(y);
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::{SliceInterleave, SliceSpread};
+
+    #[test]
+    fn it_works()
+    {
+        let arr: [usize; 23] = core::array::from_fn(|i| i);
+
+        let mut x = arr;
+        x.deinterleave(4);
+
+        let [a, b, c, d] = arr.as_slice().spread::<4>();
+        let mut i = 0;
+        for lane in [a, b, c, d]
+        {
+            for e in lane
+            {
+                assert_eq!(x[i], *e);
+                i += 1;
+            }
+        }
+
+        x.interleave(4);
+        assert_eq!(x, arr);
+    }
+}
@@ -3,25 +3,31 @@ moddef::moddef!(
         add_assign,
         argminmax,
         argreduce,
+        argsort,
+        argtopk,
         bitand_assign,
         bitor_assign,
         bitxor_assign,
         differentiate,
         div_assign,
         find,
+        group,
         integrate,
+        interleave,
         magnitude,
         mul_assign,
         neg_assign,
         not_assign,
         permute,
         rem_assign,
+        rle,
         shift,
         shl_assign,
         shr_assign,
         split,
         spread,
         sub_assign,
+        transpose,
         trim,
         visit
     }
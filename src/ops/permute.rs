@@ -1,5 +1,7 @@
 use slice_trait::Slice;
 
+use crate::error::Error;
+
 #[const_trait]
 pub trait SlicePermute<T>: Slice<Item = T>
 {
@@ -47,6 +49,286 @@ pub trait SlicePermute<T>: Slice<Item = T>
     /// assert_eq!(arr, [0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100])
     /// ```
     fn grey_code_permutation(&mut self);
+
+    /// Performs the bit-reverse permutation, or returns an error if the length is not a power of 2.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::{ops::*, error::Error};
+    /// 
+    /// let mut arr = [0b000, 0b001, 0b010, 0b011, 0b100, 0b101, 0b110, 0b111];
+    /// 
+    /// assert_eq!(arr.try_bit_rev_permutation(), Ok(()));
+    /// assert_eq!(arr, [0b000, 0b100, 0b010, 0b110, 0b001, 0b101, 0b011, 0b111]);
+    /// 
+    /// assert_eq!([1, 2, 3].try_bit_rev_permutation(), Err(Error::NotPowerOf {len: 3, radix: 2}));
+    /// ```
+    fn try_bit_rev_permutation(&mut self) -> Result<(), Error>;
+
+    /// Performs the digit-reverse permutation with any radix, or returns an error if the length is not a power of the radix.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::{ops::*, error::Error};
+    /// 
+    /// let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+    /// 
+    /// assert_eq!(arr.try_digit_rev_permutation(3), Ok(()));
+    /// assert_eq!(arr, [0, 3, 6, 1, 4, 7, 2, 5, 8]);
+    /// 
+    /// assert_eq!(arr.try_digit_rev_permutation(2), Err(Error::NotPowerOf {len: 9, radix: 2}));
+    /// assert_eq!(arr.try_digit_rev_permutation(1), Err(Error::InvalidRadix {radix: 1}));
+    /// ```
+    fn try_digit_rev_permutation(&mut self, radix: usize) -> Result<(), Error>;
+
+    /// Performs the grey code permutation, or returns an error if the length is not a power of 2.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::{ops::*, error::Error};
+    /// 
+    /// let mut arr = [0b000, 0b001, 0b010, 0b011, 0b100, 0b101, 0b110, 0b111];
+    /// 
+    /// assert_eq!(arr.try_grey_code_permutation(), Ok(()));
+    /// assert_eq!(arr, [0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]);
+    /// 
+    /// assert_eq!([1, 2, 3].try_grey_code_permutation(), Err(Error::NotPowerOf {len: 3, radix: 2}));
+    /// ```
+    fn try_grey_code_permutation(&mut self) -> Result<(), Error>;
+
+    /// Performs the mixed-radix digit-reverse permutation, as used in mixed-radix FFTs. The product of the radices must equal the length.
+    /// 
+    /// Each index `i` is written with the digits `d₀ + r₀(d₁ + r₁(d₂ + ...))`, and the element at `i` is moved to the index with the same digits in reverse order,
+    /// under the reversed sequence of radices. With equal radices, this is the same as [`digit_rev_permutation`](SlicePermute::digit_rev_permutation).
+    /// 
+    /// A suitable sequence of radices can be found with [`radix_factors`](crate::radix_factors).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = [0, 1, 2, 3, 4, 5];
+    /// 
+    /// arr.mixed_radix_digit_rev_permutation(&[2, 3]);
+    /// 
+    /// assert_eq!(arr, [0, 2, 4, 1, 3, 5]);
+    /// 
+    /// let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    /// 
+    /// arr.mixed_radix_digit_rev_permutation(&[3, 4]);
+    /// 
+    /// assert_eq!(arr, [0, 3, 6, 9, 1, 4, 7, 10, 2, 5, 8, 11]);
+    /// ```
+    fn mixed_radix_digit_rev_permutation(&mut self, radices: &[usize]);
+
+    /// Performs the mixed-radix digit-reverse permutation, or returns an error if the product of the radices does not equal the length.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::{ops::*, error::Error};
+    /// 
+    /// let mut arr = [0, 1, 2, 3, 4, 5];
+    /// 
+    /// assert_eq!(arr.try_mixed_radix_digit_rev_permutation(&[2, 3]), Ok(()));
+    /// assert_eq!(arr, [0, 2, 4, 1, 3, 5]);
+    /// 
+    /// assert_eq!(arr.try_mixed_radix_digit_rev_permutation(&[2, 2]), Err(Error::RadixProductMismatch {len: 6}));
+    /// ```
+    fn try_mixed_radix_digit_rev_permutation(&mut self, radices: &[usize]) -> Result<(), Error>;
+
+    /// Reorders the slice by an arbitrary permutation, so that element `perm[i]` ends up at position `i`.
+    /// 
+    /// This is done in-place by following the cycles of the permutation. The permutation buffer is used to keep track of which elements have been moved,
+    /// but it is restored before returning.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` does not have the same length as the slice, or if it's not a valid permutation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = ['a', 'b', 'c', 'd', 'e'];
+    /// let mut perm = [2, 0, 1, 4, 3];
+    /// 
+    /// arr.apply_permutation(&mut perm);
+    /// 
+    /// assert_eq!(arr, ['c', 'a', 'b', 'e', 'd']);
+    /// assert_eq!(perm, [2, 0, 1, 4, 3]);
+    /// ```
+    fn apply_permutation(&mut self, perm: &mut [usize]);
+
+    /// Reorders the slice by the inverse of an arbitrary permutation, so that element `i` ends up at position `perm[i]`.
+    /// 
+    /// This undoes [`apply_permutation`](SlicePermute::apply_permutation) with the same permutation.
+    /// The permutation buffer is used to keep track of which elements have been moved, but it is restored before returning.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `perm` does not have the same length as the slice, or if it's not a valid permutation.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = ['a', 'b', 'c', 'd', 'e'];
+    /// let mut perm = [2, 0, 1, 4, 3];
+    /// 
+    /// arr.apply_inverse_permutation(&mut perm);
+    /// 
+    /// assert_eq!(arr, ['b', 'c', 'a', 'e', 'd']);
+    /// 
+    /// arr.apply_permutation(&mut perm);
+    /// 
+    /// assert_eq!(arr, ['a', 'b', 'c', 'd', 'e']);
+    /// ```
+    fn apply_inverse_permutation(&mut self, perm: &mut [usize]);
+
+    /// Rearranges the slice into the next lexicographically greater permutation.
+    /// 
+    /// If the slice is already the greatest permutation, it's rearranged into the smallest one (sorted order), and `false` is returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = [1, 2, 3];
+    /// 
+    /// assert!(arr.next_permutation());
+    /// assert_eq!(arr, [1, 3, 2]);
+    /// 
+    /// assert!(arr.next_permutation());
+    /// assert_eq!(arr, [2, 1, 3]);
+    /// 
+    /// let mut arr = [3, 2, 1];
+    /// 
+    /// assert!(!arr.next_permutation());
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// ```
+    fn next_permutation(&mut self) -> bool
+    where
+        T: Ord;
+
+    /// Rearranges the slice into the next lexicographically smaller permutation.
+    /// 
+    /// If the slice is already the smallest permutation, it's rearranged into the greatest one (reverse sorted order), and `false` is returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = [2, 1, 3];
+    /// 
+    /// assert!(arr.prev_permutation());
+    /// assert_eq!(arr, [1, 3, 2]);
+    /// 
+    /// assert!(arr.prev_permutation());
+    /// assert_eq!(arr, [1, 2, 3]);
+    /// 
+    /// assert!(!arr.prev_permutation());
+    /// assert_eq!(arr, [3, 2, 1]);
+    /// ```
+    fn prev_permutation(&mut self) -> bool
+    where
+        T: Ord;
+
+    /// Finds the lexicographic rank of the permutation, among all permutations of its elements, using the Lehmer code.
+    /// 
+    /// The elements are assumed to be distinct. Returns [`None`] if the rank does not fit in a [`usize`].
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// assert_eq!([1, 2, 3].permutation_rank(), Some(0));
+    /// assert_eq!([1, 3, 2].permutation_rank(), Some(1));
+    /// assert_eq!([2, 1, 3].permutation_rank(), Some(2));
+    /// assert_eq!([3, 2, 1].permutation_rank(), Some(5));
+    /// ```
+    fn permutation_rank(&self) -> Option<usize>
+    where
+        T: Ord;
+
+    /// Rearranges the slice into the permutation with the given lexicographic rank, treating its current order as the smallest permutation.
+    /// 
+    /// This is the inverse of [`permutation_rank`](SlicePermute::permutation_rank) for a sorted slice of distinct elements.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `rank` is not less than the number of permutations of the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = [1, 2, 3];
+    /// 
+    /// arr.from_rank(2);
+    /// 
+    /// assert_eq!(arr, [2, 1, 3]);
+    /// assert_eq!(arr.permutation_rank(), Some(2));
+    /// ```
+    fn from_rank(&mut self, rank: usize);
+
+    /// Visits every permutation of the slice once, rearranging it in-place using Heap's algorithm.
+    /// 
+    /// The first permutation visited is the slice as it is. Each following permutation differs from the previous by a single swap.
+    /// Afterwards, the slice is left in the last permutation visited.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = [1, 2, 3];
+    /// 
+    /// let mut n = 0;
+    /// arr.visit_permutations(|p| {
+    ///     assert!(p.contains(&1) && p.contains(&2) && p.contains(&3));
+    ///     n += 1;
+    /// });
+    /// 
+    /// assert_eq!(n, 6);
+    /// ```
+    fn visit_permutations<F>(&mut self, visitor: F)
+    where
+        F: FnMut(&[T]) /*+ ~const Destruct*/;
+
+    /// Visits every permutation of the slice once, rearranging it in-place using Heap's algorithm, or short-circuits if visitor returns error.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = [1, 2, 3];
+    /// 
+    /// let result = arr.try_visit_permutations(|p| {
+    ///     if p[0] == 3
+    ///     {
+    ///         return Err(p[1])
+    ///     }
+    ///     Ok(())
+    /// });
+    /// 
+    /// assert!(result.is_err());
+    /// assert_eq!(arr[0], 3);
+    /// ```
+    fn try_visit_permutations<E, F>(&mut self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&[T]) -> Result<(), E> /*+ ~const Destruct*/;
 }
 
 impl<T> SlicePermute<T> for [T]
@@ -112,6 +394,508 @@ impl<T> SlicePermute<T> for [T]
             i += 1;
         }
     }
+
+    fn try_bit_rev_permutation(&mut self) -> Result<(), Error>
+    {
+        self.try_digit_rev_permutation(2)
+    }
+
+    fn try_digit_rev_permutation(&mut self, radix: usize) -> Result<(), Error>
+    {
+        let len = self.len();
+        if radix < 2
+        {
+            return Err(Error::InvalidRadix {radix})
+        }
+        if len > radix && !crate::is_power_of(len, radix)
+        {
+            return Err(Error::NotPowerOf {len, radix})
+        }
+        self.digit_rev_permutation(radix);
+        Ok(())
+    }
+
+    fn try_grey_code_permutation(&mut self) -> Result<(), Error>
+    {
+        let len = self.len();
+        if len > 2 && !len.is_power_of_two()
+        {
+            return Err(Error::NotPowerOf {len, radix: 2})
+        }
+        self.grey_code_permutation();
+        Ok(())
+    }
+
+    fn mixed_radix_digit_rev_permutation(&mut self, radices: &[usize])
+    {
+        if let Err(error) = self.try_mixed_radix_digit_rev_permutation(radices)
+        {
+            panic!("{error}")
+        }
+    }
+
+    fn try_mixed_radix_digit_rev_permutation(&mut self, radices: &[usize]) -> Result<(), Error>
+    {
+        let len = self.len();
+        let mut product: usize = 1;
+        let mut k = 0;
+        while k < radices.len()
+        {
+            if radices[k] < 2
+            {
+                return Err(Error::InvalidRadix {radix: radices[k]})
+            }
+            product = match product.checked_mul(radices[k])
+            {
+                Some(product) => product,
+                None => return Err(Error::RadixProductMismatch {len})
+            };
+            k += 1;
+        }
+        if product != len
+        {
+            return Err(Error::RadixProductMismatch {len})
+        }
+        if len <= 2
+        {
+            return Ok(())
+        }
+
+        let dest = |mut i: usize| {
+            let mut j = 0;
+            let mut k = 0;
+            while k < radices.len()
+            {
+                j = j*radices[k] + i % radices[k];
+                i /= radices[k];
+                k += 1;
+            }
+            j
+        };
+
+        let mut start = 1;
+        while start < len - 1
+        {
+            // Only rotate each cycle once, from its smallest index.
+            let mut j = dest(start);
+            while j > start
+            {
+                j = dest(j);
+            }
+            if j == start
+            {
+                let mut j = dest(start);
+                while j != start
+                {
+                    self.swap(start, j);
+                    j = dest(j);
+                }
+            }
+            start += 1;
+        }
+        Ok(())
+    }
+
+    fn apply_permutation(&mut self, perm: &mut [usize])
+    {
+        let len = self.len();
+        assert_eq!(perm.len(), len, "Permutation must have the same length as the slice.");
+        assert!(is_permutation_in_place(perm), "Not a valid permutation.");
+
+        // Visited entries are marked by inverting their bits, which always makes them out of bounds.
+        let mut i = 0;
+        while i < len
+        {
+            if perm[i] < len
+            {
+                let mut j = i;
+                loop
+                {
+                    let k = perm[j];
+                    perm[j] = !k;
+                    if k == i
+                    {
+                        break
+                    }
+                    self.swap(j, k);
+                    j = k;
+                }
+            }
+            i += 1;
+        }
+
+        unmark_permutation(perm);
+    }
+
+    fn apply_inverse_permutation(&mut self, perm: &mut [usize])
+    {
+        let len = self.len();
+        assert_eq!(perm.len(), len, "Permutation must have the same length as the slice.");
+        assert!(is_permutation_in_place(perm), "Not a valid permutation.");
+
+        let mut i = 0;
+        while i < len
+        {
+            let mut k = perm[i];
+            if k < len
+            {
+                perm[i] = !k;
+                while k != i
+                {
+                    self.swap(i, k);
+                    let next = perm[k];
+                    perm[k] = !next;
+                    k = next;
+                }
+            }
+            i += 1;
+        }
+
+        unmark_permutation(perm);
+    }
+
+    fn next_permutation(&mut self) -> bool
+    where
+        T: Ord
+    {
+        let len = self.len();
+        let mut i = len;
+        while i > 1 && self[i - 2] >= self[i - 1]
+        {
+            i -= 1;
+        }
+        if i <= 1
+        {
+            self.reverse();
+            return false
+        }
+        let pivot = i - 2;
+        let mut j = len - 1;
+        while self[j] <= self[pivot]
+        {
+            j -= 1;
+        }
+        self.swap(pivot, j);
+        self[pivot + 1..].reverse();
+        true
+    }
+
+    fn prev_permutation(&mut self) -> bool
+    where
+        T: Ord
+    {
+        let len = self.len();
+        let mut i = len;
+        while i > 1 && self[i - 2] <= self[i - 1]
+        {
+            i -= 1;
+        }
+        if i <= 1
+        {
+            self.reverse();
+            return false
+        }
+        let pivot = i - 2;
+        let mut j = len - 1;
+        while self[j] >= self[pivot]
+        {
+            j -= 1;
+        }
+        self.swap(pivot, j);
+        self[pivot + 1..].reverse();
+        true
+    }
+
+    fn permutation_rank(&self) -> Option<usize>
+    where
+        T: Ord
+    {
+        let len = self.len();
+        let mut rank: usize = 0;
+        let mut i = 0;
+        while i < len
+        {
+            let mut digit = 0;
+            let mut j = i + 1;
+            while j < len
+            {
+                if self[j] < self[i]
+                {
+                    digit += 1;
+                }
+                j += 1;
+            }
+            rank = rank.checked_mul(len - i)?.checked_add(digit)?;
+            i += 1;
+        }
+        Some(rank)
+    }
+
+    fn from_rank(&mut self, mut rank: usize)
+    {
+        let len = self.len();
+        // If the number of permutations does not fit in a usize, every rank is valid.
+        if let Some(count) = factorial(len)
+        {
+            assert!(rank < count, "Rank must be less than the number of permutations.");
+        }
+        let mut i = 0;
+        while i < len
+        {
+            // Digits with a radix that does not fit in a usize are always zero.
+            let radix = factorial(len - 1 - i);
+            let digit = match radix
+            {
+                Some(radix) => {
+                    let digit = rank/radix;
+                    rank %= radix;
+                    digit
+                },
+                None => 0
+            };
+            self[i..=i + digit].rotate_right(1);
+            i += 1;
+        }
+    }
+
+    fn visit_permutations<F>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&[T])
+    {
+        let _ = self.try_visit_permutations::<core::convert::Infallible, _>(|p| {
+            visitor(p);
+            Ok(())
+        });
+    }
+
+    fn try_visit_permutations<E, F>(&mut self, mut visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&[T]) -> Result<(), E>
+    {
+        fn heap<T, E, F>(slice: &mut [T], k: usize, visitor: &mut F) -> Result<(), E>
+        where
+            F: FnMut(&[T]) -> Result<(), E>
+        {
+            if k <= 1
+            {
+                return visitor(slice)
+            }
+            heap(slice, k - 1, visitor)?;
+            let mut i = 0;
+            while i < k - 1
+            {
+                if k % 2 == 0
+                {
+                    slice.swap(i, k - 1);
+                }
+                else
+                {
+                    slice.swap(0, k - 1);
+                }
+                heap(slice, k - 1, visitor)?;
+                i += 1;
+            }
+            Ok(())
+        }
+
+        let len = self.len();
+        heap(self, len, &mut visitor)
+    }
+}
+
+fn factorial(n: usize) -> Option<usize>
+{
+    let mut f: usize = 1;
+    let mut i = 2;
+    while i <= n
+    {
+        f = f.checked_mul(i)?;
+        i += 1;
+    }
+    Some(f)
+}
+
+/// Checks if the slice is a permutation of the indices `0..perm.len()`, meaning each index occurs exactly once.
+/// 
+/// This allocates a bitset of `perm.len()` bits. Without the `alloc` feature, use [`is_permutation_in_place`] instead.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use slice_ops::ops::*;
+/// 
+/// assert!(is_permutation(&[2, 0, 1, 4, 3]));
+/// assert!(!is_permutation(&[2, 0, 2, 4, 3]));
+/// assert!(!is_permutation(&[2, 0, 1, 5, 3]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn is_permutation(perm: &[usize]) -> bool
+{
+    // By the pigeonhole principle, distinct indices that are all in bounds cover every index.
+    let len = perm.len();
+    let mut seen = alloc::vec![0u64; len.div_ceil(64)];
+    let mut i = 0;
+    while i < len
+    {
+        let k = perm[i];
+        if k >= len || seen[k/64] & (1 << (k % 64)) != 0
+        {
+            return false
+        }
+        seen[k/64] |= 1 << (k % 64);
+        i += 1;
+    }
+    true
+}
+
+/// Writes the index map of the bit-reverse permutation into `indices`, such that `indices[i]` is the index that ends up at position `i`.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use slice_ops::ops::*;
+/// 
+/// let mut indices = [0; 8];
+/// 
+/// bit_rev_permutation_indices(&mut indices);
+/// 
+/// assert_eq!(indices, [0, 4, 2, 6, 1, 5, 3, 7]);
+/// ```
+pub fn bit_rev_permutation_indices(indices: &mut [usize])
+{
+    identity_permutation(indices);
+    indices.bit_rev_permutation()
+}
+
+/// Writes the index map of the digit-reverse permutation with the given radix into `indices`, such that `indices[i]` is the index that ends up at
+/// position `i`.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use slice_ops::ops::*;
+/// 
+/// let mut indices = [0; 9];
+/// 
+/// digit_rev_permutation_indices(&mut indices, 3);
+/// 
+/// assert_eq!(indices, [0, 3, 6, 1, 4, 7, 2, 5, 8]);
+/// ```
+pub fn digit_rev_permutation_indices(indices: &mut [usize], radix: usize)
+{
+    identity_permutation(indices);
+    indices.digit_rev_permutation(radix)
+}
+
+/// Writes the index map of the grey code permutation into `indices`, such that `indices[i]` is the index that ends up at position `i`.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use slice_ops::ops::*;
+/// 
+/// let mut indices = [0; 8];
+/// 
+/// grey_code_permutation_indices(&mut indices);
+/// 
+/// assert_eq!(indices, [0, 1, 3, 2, 6, 7, 5, 4]);
+/// ```
+pub fn grey_code_permutation_indices(indices: &mut [usize])
+{
+    identity_permutation(indices);
+    indices.grey_code_permutation()
+}
+
+/// Writes the identity permutation into `indices`.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use slice_ops::ops::*;
+/// 
+/// let mut indices = [0; 4];
+/// 
+/// identity_permutation(&mut indices);
+/// 
+/// assert_eq!(indices, [0, 1, 2, 3]);
+/// ```
+pub fn identity_permutation(indices: &mut [usize])
+{
+    let len = indices.len();
+    let mut i = 0;
+    while i < len
+    {
+        indices[i] = i;
+        i += 1;
+    }
+}
+
+/// Checks if the slice is a permutation of the indices `0..perm.len()`, meaning each index occurs exactly once, without allocating.
+/// 
+/// Visited entries are marked by inverting their bits, so the slice has to be mutable. It is restored before returning.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use slice_ops::ops::*;
+/// 
+/// let mut perm = [2, 0, 1, 4, 3];
+/// 
+/// assert!(is_permutation_in_place(&mut perm));
+/// assert_eq!(perm, [2, 0, 1, 4, 3]);
+/// 
+/// assert!(!is_permutation_in_place(&mut [2, 0, 2, 4, 3]));
+/// assert!(!is_permutation_in_place(&mut [2, 0, 1, 5, 3]));
+/// ```
+pub fn is_permutation_in_place(perm: &mut [usize]) -> bool
+{
+    let len = perm.len();
+    let mut i = 0;
+    while i < len
+    {
+        if perm[i] >= len
+        {
+            return false
+        }
+        i += 1;
+    }
+
+    let mut valid = true;
+    let mut i = 0;
+    while valid && i < len
+    {
+        let mut j = i;
+        while perm[j] < len
+        {
+            let k = perm[j];
+            perm[j] = !k;
+            if k != i && perm[k] >= len
+            {
+                valid = false;
+                break
+            }
+            j = k;
+        }
+        i += 1;
+    }
+
+    unmark_permutation(perm);
+    valid
+}
+
+/// Restores all entries of a permutation buffer that were marked as visited by inverting their bits.
+fn unmark_permutation(perm: &mut [usize])
+{
+    let len = perm.len();
+    let mut i = 0;
+    while i < len
+    {
+        if perm[i] >= len
+        {
+            perm[i] = !perm[i];
+        }
+        i += 1;
+    }
 }
 
 #[cfg(test)]
@@ -128,4 +912,122 @@ mod test
 
         assert_eq!(arr, [0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100])
     }
+
+    #[test]
+    fn test_apply_permutation()
+    {
+        let mut perm = [0; 16];
+        super::bit_rev_permutation_indices(&mut perm);
+        assert!(super::is_permutation_in_place(&mut perm));
+
+        let mut arr: [usize; 16] = core::array::from_fn(|i| i*i);
+        let mut expected = arr;
+        expected.bit_rev_permutation();
+
+        arr.apply_permutation(&mut perm);
+        assert_eq!(arr, expected);
+
+        arr.apply_inverse_permutation(&mut perm);
+        assert_eq!(arr, core::array::from_fn(|i| i*i));
+
+        assert!(!super::is_permutation_in_place(&mut [0, 2, 2]));
+        assert!(!super::is_permutation_in_place(&mut [0, 3, 1]));
+
+        // An invalid permutation is rejected before anything is moved.
+        for bad in [[1, 2, 0, 0], [3, 0, 1, 1], [0, 1, 2, 4]]
+        {
+            let mut arr = ['a', 'b', 'c', 'd'];
+            let mut perm = bad;
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| arr.apply_permutation(&mut perm)));
+            assert!(result.is_err());
+            assert_eq!(arr, ['a', 'b', 'c', 'd']);
+            assert_eq!(perm, bad);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| arr.apply_inverse_permutation(&mut perm)));
+            assert!(result.is_err());
+            assert_eq!(arr, ['a', 'b', 'c', 'd']);
+            assert_eq!(perm, bad);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_is_permutation()
+    {
+        let mut perm = [0; 100];
+        super::digit_rev_permutation_indices(&mut perm, 10);
+        assert!(super::is_permutation(&perm));
+        assert!(super::is_permutation(&[]));
+
+        perm[99] = perm[0];
+        assert!(!super::is_permutation(&perm));
+        assert!(!super::is_permutation_in_place(&mut perm));
+    }
+
+    #[test]
+    fn test_mixed_radix_digit_rev_permutation()
+    {
+        let mut arr: [usize; 81] = core::array::from_fn(|i| i);
+        let mut expected = arr;
+        expected.digit_rev_permutation(3);
+        arr.mixed_radix_digit_rev_permutation(&[3, 3, 3, 3]);
+        assert_eq!(arr, expected);
+
+        let mut radices = [0; usize::BITS as usize];
+        let radices = crate::radix_factors(60, &mut radices);
+        assert_eq!(radices, [4, 3, 5]);
+
+        let mut arr: [usize; 60] = core::array::from_fn(|i| i);
+        arr.mixed_radix_digit_rev_permutation(radices);
+        for (i, &e) in arr.iter().enumerate()
+        {
+            assert_eq!(i, e/12 + 5*(e/4 % 3) + 15*(e % 4));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_rank_empty()
+    {
+        let mut arr: [u8; 0] = [];
+        arr.from_rank(0);
+        arr.from_rank(5);
+    }
+
+    #[test]
+    fn test_permutation_rank()
+    {
+        let mut arr = [0, 1, 2, 3, 4];
+        let mut rank = 0;
+        loop
+        {
+            assert_eq!(arr.permutation_rank(), Some(rank));
+
+            let mut unranked = [0, 1, 2, 3, 4];
+            unranked.from_rank(rank);
+            assert_eq!(unranked, arr);
+
+            if !arr.next_permutation()
+            {
+                break
+            }
+            rank += 1;
+        }
+        assert_eq!(rank, 5*4*3*2 - 1);
+        assert_eq!(arr, [0, 1, 2, 3, 4]);
+
+        // An out-of-range rank is rejected before anything is moved.
+        let mut unranked = [0, 1, 2, 3, 4];
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unranked.from_rank(5*4*3*2)));
+        assert!(result.is_err());
+        assert_eq!(unranked, [0, 1, 2, 3, 4]);
+
+        let mut count = 0;
+        let mut seen = std::collections::HashSet::new();
+        arr.visit_permutations(|p| {
+            count += 1;
+            seen.insert(p.to_vec());
+        });
+        assert_eq!(count, 120);
+        assert_eq!(seen.len(), 120);
+    }
 }
\ No newline at end of file
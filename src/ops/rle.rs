@@ -0,0 +1,308 @@
+use core::iter::FusedIterator;
+
+use slice_trait::Slice;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+#[const_trait]
+pub trait SliceRle<T>: Slice<Item = T>
+{
+    /// Returns an iterator over the runs of equal values in the slice, as pairs of the value and the length of the run.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [7, 7, 7, 1, 3, 3];
+    /// 
+    /// let mut runs = x.run_lengths();
+    /// 
+    /// assert_eq!(runs.next(), Some((&7, 3)));
+    /// assert_eq!(runs.next(), Some((&1, 1)));
+    /// assert_eq!(runs.next(), Some((&3, 2)));
+    /// assert_eq!(runs.next(), None);
+    /// ```
+    fn run_lengths(&self) -> RunLengths<'_, T>
+    where
+        T: PartialEq;
+
+    /// Run-length encodes the slice into `out`, as pairs of a value and the number of times it repeats.
+    /// 
+    /// Returns the number of runs written.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `out` is too short to hold all the runs.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [7, 7, 7, 1, 3, 3];
+    /// 
+    /// let mut runs = [(0, 0); 4];
+    /// 
+    /// let n = x.rle_encode_into(&mut runs);
+    /// 
+    /// assert_eq!(n, 3);
+    /// assert_eq!(runs[..n], [(7, 3), (1, 1), (3, 2)]);
+    /// ```
+    fn rle_encode_into(&self, out: &mut [(T, usize)]) -> usize
+    where
+        T: PartialEq + Clone;
+    /// Run-length encodes the slice, as pairs of a value and the number of times it repeats.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = ['a', 'a', 'b', 'a'];
+    /// 
+    /// let runs = x.rle_encode_boxed();
+    /// 
+    /// assert_eq!(*runs, [('a', 2), ('b', 1), ('a', 1)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn rle_encode_boxed(&self) -> Box<[(T, usize)]>
+    where
+        T: PartialEq + Clone;
+}
+
+#[const_trait]
+pub trait SliceRleDecode<T>: Slice<Item = (T, usize)>
+{
+    /// Returns the length of the slice that the runs decode to.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the decoded length overflows a `usize`, which can only happen for corrupt run lengths.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let runs = [(7, 3), (1, 1), (3, 2)];
+    /// 
+    /// assert_eq!(runs.rle_decoded_len(), 6);
+    /// ```
+    fn rle_decoded_len(&self) -> usize;
+
+    /// Decodes run-length encoded pairs of a value and the number of times it repeats into `out`.
+    /// 
+    /// Returns the number of values written.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `out` is too short to hold all the decoded values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let runs = [(7, 3), (1, 1), (3, 2)];
+    /// 
+    /// let mut x = [0; 6];
+    /// 
+    /// let n = runs.rle_decode_into(&mut x);
+    /// 
+    /// assert_eq!(n, 6);
+    /// assert_eq!(x, [7, 7, 7, 1, 3, 3]);
+    /// ```
+    fn rle_decode_into(&self, out: &mut [T]) -> usize
+    where
+        T: Clone;
+    /// Decodes run-length encoded pairs of a value and the number of times it repeats.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the decoded length overflows a `usize`, before allocating anything.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let x = [1.0, 1.0, 1.0, 0.5, 0.0, 0.0];
+    /// 
+    /// let runs = x.rle_encode_boxed();
+    /// 
+    /// assert_eq!(*runs.rle_decode_boxed(), x);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn rle_decode_boxed(&self) -> Box<[T]>
+    where
+        T: Clone;
+}
+
+impl<T> SliceRle<T> for [T]
+{
+    fn run_lengths(&self) -> RunLengths<'_, T>
+    where
+        T: PartialEq
+    {
+        RunLengths {
+            rest: self
+        }
+    }
+
+    fn rle_encode_into(&self, out: &mut [(T, usize)]) -> usize
+    where
+        T: PartialEq + Clone
+    {
+        let mut n = 0;
+        for (x, len) in self.run_lengths()
+        {
+            assert!(n < out.len(), "Output is too short to hold all the runs.");
+            out[n] = (x.clone(), len);
+            n += 1;
+        }
+        n
+    }
+    #[cfg(feature = "alloc")]
+    fn rle_encode_boxed(&self) -> Box<[(T, usize)]>
+    where
+        T: PartialEq + Clone
+    {
+        let mut out = Vec::with_capacity(self.run_lengths().count());
+        out.extend(self.run_lengths().map(|(x, len)| (x.clone(), len)));
+        out.into_boxed_slice()
+    }
+}
+
+impl<T> SliceRleDecode<T> for [(T, usize)]
+{
+    fn rle_decoded_len(&self) -> usize
+    {
+        let mut len = 0;
+        let mut i = 0;
+        while i < self.len()
+        {
+            len = match len.checked_add(self[i].1)
+            {
+                Some(len) => len,
+                None => panic!("Decoded length overflows usize.")
+            };
+            i += 1;
+        }
+        len
+    }
+
+    fn rle_decode_into(&self, out: &mut [T]) -> usize
+    where
+        T: Clone
+    {
+        let mut n = 0;
+        let mut i = 0;
+        while i < self.len()
+        {
+            let (x, len) = &self[i];
+            assert!(*len <= out.len() - n, "Output is too short to hold all the decoded values.");
+            out[n..n + len].fill(x.clone());
+            n += len;
+            i += 1;
+        }
+        n
+    }
+    #[cfg(feature = "alloc")]
+    fn rle_decode_boxed(&self) -> Box<[T]>
+    where
+        T: Clone
+    {
+        let mut out = Vec::with_capacity(self.rle_decoded_len());
+        let mut i = 0;
+        while i < self.len()
+        {
+            let (x, len) = &self[i];
+            out.extend(core::iter::repeat_n(x, *len).cloned());
+            i += 1;
+        }
+        out.into_boxed_slice()
+    }
+}
+
+/// An iterator over the runs of equal values in a slice, as pairs of the value and the length of the run.
+/// 
+/// Returned by [`run_lengths`](SliceRle::run_lengths).
+#[derive(Clone, Debug)]
+pub struct RunLengths<'a, T>
+{
+    rest: &'a [T]
+}
+
+impl<'a, T> Iterator for RunLengths<'a, T>
+where
+    T: PartialEq
+{
+    type Item = (&'a T, usize);
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let x = self.rest.first()?;
+        let mut len = 1;
+        while len < self.rest.len() && self.rest[len] == *x
+        {
+            len += 1;
+        }
+        self.rest = &self.rest[len..];
+        Some((x, len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.rest.len().min(1), Some(self.rest.len()))
+    }
+}
+impl<'a, T> FusedIterator for RunLengths<'a, T>
+where
+    T: PartialEq
+{
+
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::{SliceDifferentiate, SliceIntegrate, SliceRle, SliceRleDecode};
+
+    #[test]
+    fn it_works()
+    {
+        // The deltas of a ramp are constant after the first sample.
+        let ramp: [i32; 16] = core::array::from_fn(|i| 3*i as i32 + 1);
+        let mut x = ramp;
+        x.differentiate();
+
+        let mut runs = [(0, 0); 2];
+        assert_eq!(x.rle_encode_into(&mut runs), 2);
+        assert_eq!(runs, [(1, 1), (3, 15)]);
+
+        let mut y = [0; 16];
+        assert_eq!(runs.rle_decode_into(&mut y), 16);
+        y.integrate();
+        assert_eq!(y, ramp);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(empty.run_lengths().next(), None);
+        assert_eq!(empty.rle_encode_into(&mut []), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn encode_too_short()
+    {
+        [1, 2, 3].rle_encode_into(&mut [(0, 0); 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn decoded_len_overflow()
+    {
+        [(1, usize::MAX), (2, 1)].rle_decoded_len();
+    }
+}
@@ -18,6 +18,20 @@ pub trait SliceShift<T>: Slice<Item = T>
     /// assert_eq!(register, [6, 5, 4, 3, 2, 1]);
     /// assert_eq!(io, [9, 8, 7]);
     /// ```
+    /// 
+    /// If `items` is longer than the slice, the whole slice is shifted out, followed by the first items that were shifted in.
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut register = [5, 4];
+    /// let mut io = [3, 2, 1];
+    /// 
+    /// register.shift_many_left(&mut io);
+    /// 
+    /// assert_eq!(register, [2, 1]);
+    /// assert_eq!(io, [5, 4, 3]);
+    /// ```
     fn shift_many_left(&mut self, items: &mut [T]);
     
     /// Shifts the entire slice as a SISO shift register with mutliple values to the left. The output is given in-place in `ìtems`.
@@ -35,6 +49,20 @@ pub trait SliceShift<T>: Slice<Item = T>
     /// assert_eq!(register, [1, 2, 3, 4, 5, 6]);
     /// assert_eq!(io, [7, 8, 9]);
     /// ```
+    /// 
+    /// If `items` is longer than the slice, the last items that were shifted in are shifted out, followed by the whole slice.
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut register = [4, 5];
+    /// let mut io = [1, 2, 3];
+    /// 
+    /// register.shift_many_right(&mut io);
+    /// 
+    /// assert_eq!(register, [1, 2]);
+    /// assert_eq!(io, [3, 4, 5]);
+    /// ```
     fn shift_many_right(&mut self, items: &mut [T]);
     
     /// Shifts the entire slice as a SISO shift register to the left. The output is given in-place in `ìtem`.
@@ -70,6 +98,90 @@ pub trait SliceShift<T>: Slice<Item = T>
     /// assert_eq!(io, 4);
     /// ```
     fn shift_right(&mut self, item: &mut T);
+
+    /// Shifts the entire slice `n` steps to the left, discarding the values shifted out and filling the vacated end with clones of `value`.
+    /// 
+    /// If `n` is at least the length of the slice, the whole slice is filled.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut register = [1, 2, 3, 4, 5];
+    /// 
+    /// register.shift_left_fill(2, 0);
+    /// 
+    /// assert_eq!(register, [3, 4, 5, 0, 0]);
+    /// ```
+    fn shift_left_fill(&mut self, n: usize, value: T)
+    where
+        T: Clone;
+
+    /// Shifts the entire slice `n` steps to the right, discarding the values shifted out and filling the vacated start with clones of `value`.
+    /// 
+    /// If `n` is at least the length of the slice, the whole slice is filled.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut register = [1, 2, 3, 4, 5];
+    /// 
+    /// register.shift_right_fill(2, 0);
+    /// 
+    /// assert_eq!(register, [0, 0, 1, 2, 3]);
+    /// ```
+    fn shift_right_fill(&mut self, n: usize, value: T)
+    where
+        T: Clone;
+
+    /// Shifts the entire slice `n` steps to the left, discarding the values shifted out and filling the vacated end with values returned by `fill`, from left to right.
+    /// 
+    /// If `n` is at least the length of the slice, the whole slice is filled.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut register = [1, 2, 3, 4, 5];
+    /// 
+    /// let mut next = 5;
+    /// register.shift_left_with(3, || {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// 
+    /// assert_eq!(register, [4, 5, 6, 7, 8]);
+    /// ```
+    fn shift_left_with<F>(&mut self, n: usize, fill: F)
+    where
+        F: FnMut() -> T;
+
+    /// Shifts the entire slice `n` steps to the right, discarding the values shifted out and filling the vacated start with values returned by `fill`, from left to right.
+    /// 
+    /// If `n` is at least the length of the slice, the whole slice is filled.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut register = [1, 2, 3, 4, 5];
+    /// 
+    /// let mut next = -3;
+    /// register.shift_right_with(2, || {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// 
+    /// assert_eq!(register, [-2, -1, 1, 2, 3]);
+    /// ```
+    fn shift_right_with<F>(&mut self, n: usize, fill: F)
+    where
+        F: FnMut() -> T;
 }
 
 impl<T> SliceShift<T> for [T]
@@ -101,7 +213,7 @@ impl<T> SliceShift<T> for [T]
     fn shift_left(&mut self, item: &mut T)
     {
         let l = self.len();
-        if l <= 1
+        if l == 0
         {
             return;
         }
@@ -118,7 +230,7 @@ impl<T> SliceShift<T> for [T]
     fn shift_right(&mut self, item: &mut T)
     {
         let l = self.len();
-        if l <= 1
+        if l == 0
         {
             return;
         }
@@ -131,14 +243,121 @@ impl<T> SliceShift<T> for [T]
             core::ptr::swap_nonoverlapping(p.start, item as *mut T, 1);
         }
     }
+
+    fn shift_left_fill(&mut self, n: usize, value: T)
+    where
+        T: Clone
+    {
+        let len = self.len();
+        let n = n.min(len);
+        self.rotate_left(n);
+        self[len - n..].fill(value)
+    }
+
+    fn shift_right_fill(&mut self, n: usize, value: T)
+    where
+        T: Clone
+    {
+        let n = n.min(self.len());
+        self.rotate_right(n);
+        self[..n].fill(value)
+    }
+
+    fn shift_left_with<F>(&mut self, n: usize, fill: F)
+    where
+        F: FnMut() -> T
+    {
+        let len = self.len();
+        let n = n.min(len);
+        self.rotate_left(n);
+        self[len - n..].fill_with(fill)
+    }
+
+    fn shift_right_with<F>(&mut self, n: usize, fill: F)
+    where
+        F: FnMut() -> T
+    {
+        let n = n.min(self.len());
+        self.rotate_right(n);
+        self[..n].fill_with(fill)
+    }
 }
 
 #[cfg(test)]
 mod test
 {
+    use crate::ops::SliceShift;
+
     #[test]
     fn it_works()
     {
-        
+        let mut register = [1];
+        let mut io = 2;
+        register.shift_left(&mut io);
+        assert_eq!((register, io), ([2], 1));
+        register.shift_right(&mut io);
+        assert_eq!((register, io), ([1], 2));
+
+        for len in 0..6
+        {
+            for m in 0..9
+            {
+                let register: Vec<usize> = (0..len).collect();
+                let io: Vec<usize> = (len..len + m).collect();
+
+                let mut left = register.clone();
+                let mut left_io = io.clone();
+                left.shift_many_left(&mut left_io);
+                let stream: Vec<usize> = register.iter().chain(io.iter()).copied().collect();
+                assert_eq!(left_io, stream[..m]);
+                assert_eq!(left, stream[m..]);
+
+                let mut right = register.clone();
+                let mut right_io = io.clone();
+                right.shift_many_right(&mut right_io);
+                let stream: Vec<usize> = io.iter().chain(register.iter()).copied().collect();
+                assert_eq!(right, stream[..len]);
+                assert_eq!(right_io, stream[len..]);
+            }
+        }
+
+        for len in 0..6
+        {
+            for n in 0..9
+            {
+                let register: Vec<usize> = (0..len).collect();
+                let k = n.min(len);
+
+                let mut left = register.clone();
+                left.shift_left_fill(n, usize::MAX);
+                let expected: Vec<usize> = (0..len).map(|i| if i + k < len {i + k} else {usize::MAX}).collect();
+                assert_eq!(left, expected);
+
+                let mut right = register.clone();
+                right.shift_right_fill(n, usize::MAX);
+                let expected: Vec<usize> = (0..len).map(|i| if i >= k {i - k} else {usize::MAX}).collect();
+                assert_eq!(right, expected);
+
+                let mut left = register.clone();
+                let mut next = 100;
+                left.shift_left_with(n, || {
+                    next += 1;
+                    next - 1
+                });
+                let expected: Vec<usize> = (0..len).map(|i| if i + k < len {i + k} else {100 + i + k - len}).collect();
+                assert_eq!(left, expected);
+                assert_eq!(next, 100 + k);
+
+                let mut right = register.clone();
+                let mut next = 100;
+                right.shift_right_with(n, || {
+                    next += 1;
+                    next - 1
+                });
+                let expected: Vec<usize> = (0..len).map(|i| if i >= k {i - k} else {100 + i}).collect();
+                assert_eq!(right, expected);
+                assert_eq!(next, 100 + k);
+            }
+        }
     }
 }
\ No newline at end of file
@@ -1,5 +1,9 @@
+use core::iter::FusedIterator;
+
 use slice_trait::Slice;
 
+use crate::error::Error;
+
 #[const_trait]
 pub trait SliceSplit<T>: Slice<Item = T>
 {
@@ -72,6 +76,285 @@ pub trait SliceSplit<T>: Slice<Item = T>
     /// assert_eq!(right, &mut [2, 3, 4]);
     /// ```
     fn rsplit_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]);
+
+    /// Splits the slice in two parts, from the right, or returns an error if `mid` is greater than the length of the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::{ops::*, error::Error};
+    /// 
+    /// let values = [1, 2, 3, 4];
+    /// 
+    /// let (left, right) = values.try_rsplit_at(3).unwrap();
+    /// 
+    /// assert_eq!(left, &[1]);
+    /// assert_eq!(right, &[2, 3, 4]);
+    /// 
+    /// assert_eq!(values.try_rsplit_at(5), Err(Error::SplitOutOfBounds {mid: 5, len: 4}));
+    /// ```
+    fn try_rsplit_at(&self, mid: usize) -> Result<(&[T], &[T]), Error>;
+    /// Splits the slice in two parts, from the right, or returns an error if `mid` is greater than the length of the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::{ops::*, error::Error};
+    /// 
+    /// let mut values = [1, 2, 3, 4];
+    /// 
+    /// let (left, right) = values.try_rsplit_at_mut(3).unwrap();
+    /// 
+    /// assert_eq!(left, &mut [1]);
+    /// assert_eq!(right, &mut [2, 3, 4]);
+    /// 
+    /// assert!(values.try_rsplit_at_mut(5).is_err());
+    /// ```
+    fn try_rsplit_at_mut(&mut self, mid: usize) -> Result<(&mut [T], &mut [T]), Error>;
+
+    /// Splits the first `N` elements off as an array reference, or returns `None` if the slice is shorter than `N`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let values = [1, 2, 3, 4];
+    /// 
+    /// let (left, right) = values.split_array_ref::<3>().unwrap();
+    /// 
+    /// assert_eq!(left, &[1, 2, 3]);
+    /// assert_eq!(right, &[4]);
+    /// 
+    /// assert_eq!(values.split_array_ref::<5>(), None);
+    /// ```
+    fn split_array_ref<const N: usize>(&self) -> Option<(&[T; N], &[T])>;
+    /// Splits the first `N` elements off as a mutable array reference, or returns `None` if the slice is shorter than `N`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut values = [1, 2, 3, 4];
+    /// 
+    /// let (left, right) = values.split_array_mut::<1>().unwrap();
+    /// 
+    /// assert_eq!(left, &mut [1]);
+    /// assert_eq!(right, &mut [2, 3, 4]);
+    /// ```
+    fn split_array_mut<const N: usize>(&mut self) -> Option<(&mut [T; N], &mut [T])>;
+    /// Splits the last `N` elements off as an array reference, or returns `None` if the slice is shorter than `N`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let values = [1, 2, 3, 4];
+    /// 
+    /// let (left, right) = values.rsplit_array_ref::<3>().unwrap();
+    /// 
+    /// assert_eq!(left, &[1]);
+    /// assert_eq!(right, &[2, 3, 4]);
+    /// 
+    /// assert_eq!(values.rsplit_array_ref::<5>(), None);
+    /// ```
+    fn rsplit_array_ref<const N: usize>(&self) -> Option<(&[T], &[T; N])>;
+    /// Splits the last `N` elements off as a mutable array reference, or returns `None` if the slice is shorter than `N`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut values = [1, 2, 3, 4];
+    /// 
+    /// let (left, right) = values.rsplit_array_mut::<1>().unwrap();
+    /// 
+    /// assert_eq!(left, &mut [1, 2, 3]);
+    /// assert_eq!(right, &mut [4]);
+    /// ```
+    fn rsplit_array_mut<const N: usize>(&mut self) -> Option<(&mut [T], &mut [T; N])>;
+
+    /// Splits the slice into as many `N`-element arrays as possible, and a remainder shorter than `N`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `N` is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let values = [1, 2, 3, 4, 5, 6, 7];
+    /// 
+    /// let (chunks, rest) = values.split_array_chunks::<3>();
+    /// 
+    /// assert_eq!(chunks, &[[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(rest, &[7]);
+    /// ```
+    fn split_array_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]);
+    /// Splits the slice into as many mutable `N`-element arrays as possible, and a remainder shorter than `N`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `N` is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut values = [1, 2, 3, 4, 5];
+    /// 
+    /// let (chunks, rest) = values.split_array_chunks_mut::<2>();
+    /// chunks[1].reverse();
+    /// rest[0] = 0;
+    /// 
+    /// assert_eq!(values, [1, 2, 4, 3, 0]);
+    /// ```
+    fn split_array_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]);
+
+    /// Splits the slice at each of the given indices, yielding `mids.len() + 1` subslices.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `mids` is not sorted in ascending order, or if any index is greater than the length of the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let values = [1, 2, 3, 4, 5, 6];
+    /// 
+    /// let mut parts = values.split_at_many(&[1, 1, 4]);
+    /// 
+    /// assert_eq!(parts.next(), Some(&[1][..]));
+    /// assert_eq!(parts.next(), Some(&[][..]));
+    /// assert_eq!(parts.next(), Some(&[2, 3, 4][..]));
+    /// assert_eq!(parts.next(), Some(&[5, 6][..]));
+    /// assert_eq!(parts.next(), None);
+    /// ```
+    fn split_at_many<'a>(&'a self, mids: &'a [usize]) -> SplitAtMany<'a, T>;
+    /// Splits the slice into disjoint mutable subslices at each of the given indices, yielding `mids.len() + 1` subslices.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `mids` is not sorted in ascending order, or if any index is greater than the length of the slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut values = [1, 2, 3, 4, 5, 6];
+    /// 
+    /// for (i, part) in values.split_at_many_mut(&[2, 3]).enumerate()
+    /// {
+    ///     part.fill(i);
+    /// }
+    /// 
+    /// assert_eq!(values, [0, 0, 1, 2, 2, 2]);
+    /// ```
+    fn split_at_many_mut<'a>(&'a mut self, mids: &'a [usize]) -> SplitAtManyMut<'a, T>;
+
+    /// Splits the slice into `n` subslices with lengths differing by at most one.
+    /// 
+    /// The longer subslices come first.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `n` is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let values = [1, 2, 3, 4, 5, 6, 7];
+    /// 
+    /// let mut parts = values.split_into_n(3);
+    /// 
+    /// assert_eq!(parts.next(), Some(&[1, 2, 3][..]));
+    /// assert_eq!(parts.next(), Some(&[4, 5][..]));
+    /// assert_eq!(parts.next(), Some(&[6, 7][..]));
+    /// assert_eq!(parts.next(), None);
+    /// ```
+    fn split_into_n(&self, n: usize) -> SplitIntoN<'_, T>;
+    /// Splits the slice into `n` disjoint mutable subslices with lengths differing by at most one.
+    /// 
+    /// The longer subslices come first.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `n` is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut values = [0; 5];
+    /// 
+    /// for (i, part) in values.split_into_n_mut(2).enumerate()
+    /// {
+    ///     part.fill(i);
+    /// }
+    /// 
+    /// assert_eq!(values, [0, 0, 0, 1, 1]);
+    /// ```
+    fn split_into_n_mut(&mut self, n: usize) -> SplitIntoNMut<'_, T>;
+
+    /// Splits the slice into `weights.len()` subslices, with lengths proportional to the given weights.
+    /// 
+    /// Each split point is rounded down, so the subslices always cover the whole slice.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the weights sum to zero, or if their sum overflows.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// 
+    /// let mut parts = values.split_by_ratio(&[1, 0, 3, 1]);
+    /// 
+    /// assert_eq!(parts.next(), Some(&[1, 2][..]));
+    /// assert_eq!(parts.next(), Some(&[][..]));
+    /// assert_eq!(parts.next(), Some(&[3, 4, 5, 6, 7, 8][..]));
+    /// assert_eq!(parts.next(), Some(&[9, 10][..]));
+    /// assert_eq!(parts.next(), None);
+    /// ```
+    fn split_by_ratio<'a>(&'a self, weights: &'a [usize]) -> SplitByRatio<'a, T>;
+    /// Splits the slice into `weights.len()` disjoint mutable subslices, with lengths proportional to the given weights.
+    /// 
+    /// Each split point is rounded down, so the subslices always cover the whole slice.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the weights sum to zero, or if their sum overflows.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut values = [0; 6];
+    /// 
+    /// for (i, part) in values.split_by_ratio_mut(&[2, 1]).enumerate()
+    /// {
+    ///     part.fill(i);
+    /// }
+    /// 
+    /// assert_eq!(values, [0, 0, 0, 0, 1, 1]);
+    /// ```
+    fn split_by_ratio_mut<'a>(&'a mut self, weights: &'a [usize]) -> SplitByRatioMut<'a, T>;
 }
 
 impl<T> const SliceSplit<T> for [T]
@@ -117,14 +400,452 @@ impl<T> const SliceSplit<T> for [T]
         assert!(mid <= self.len());
         self.split_at_mut(self.len() - mid)
     }
+
+    fn try_rsplit_at(&self, mid: usize) -> Result<(&[T], &[T]), Error>
+    {
+        let len = self.len();
+        if mid > len
+        {
+            return Err(Error::SplitOutOfBounds {mid, len})
+        }
+        Ok(self.split_at(len - mid))
+    }
+    fn try_rsplit_at_mut(&mut self, mid: usize) -> Result<(&mut [T], &mut [T]), Error>
+    {
+        let len = self.len();
+        if mid > len
+        {
+            return Err(Error::SplitOutOfBounds {mid, len})
+        }
+        Ok(self.split_at_mut(len - mid))
+    }
+
+    fn split_array_ref<const N: usize>(&self) -> Option<(&[T; N], &[T])>
+    {
+        self.split_first_chunk()
+    }
+    fn split_array_mut<const N: usize>(&mut self) -> Option<(&mut [T; N], &mut [T])>
+    {
+        self.split_first_chunk_mut()
+    }
+    fn rsplit_array_ref<const N: usize>(&self) -> Option<(&[T], &[T; N])>
+    {
+        self.split_last_chunk()
+    }
+    fn rsplit_array_mut<const N: usize>(&mut self) -> Option<(&mut [T], &mut [T; N])>
+    {
+        self.split_last_chunk_mut()
+    }
+
+    fn split_array_chunks<const N: usize>(&self) -> (&[[T; N]], &[T])
+    {
+        assert!(N != 0, "Chunk size must be nonzero.");
+        let n = self.len()/N;
+        let (chunks, rest) = self.split_at(n*N);
+        (unsafe {core::slice::from_raw_parts(chunks.as_ptr().cast(), n)}, rest)
+    }
+    fn split_array_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T])
+    {
+        assert!(N != 0, "Chunk size must be nonzero.");
+        let n = self.len()/N;
+        let (chunks, rest) = self.split_at_mut(n*N);
+        (unsafe {core::slice::from_raw_parts_mut(chunks.as_mut_ptr().cast(), n)}, rest)
+    }
+
+    fn split_at_many<'a>(&'a self, mids: &'a [usize]) -> SplitAtMany<'a, T>
+    {
+        check_mids(mids, self.len());
+        SplitAtMany {
+            rest: self,
+            mids,
+            offset: 0,
+            finished: false
+        }
+    }
+    fn split_at_many_mut<'a>(&'a mut self, mids: &'a [usize]) -> SplitAtManyMut<'a, T>
+    {
+        check_mids(mids, self.len());
+        SplitAtManyMut {
+            rest: self,
+            mids,
+            offset: 0,
+            finished: false
+        }
+    }
+
+    fn split_into_n(&self, n: usize) -> SplitIntoN<'_, T>
+    {
+        assert!(n != 0, "Cannot split into zero parts.");
+        SplitIntoN {
+            rest: self,
+            n
+        }
+    }
+    fn split_into_n_mut(&mut self, n: usize) -> SplitIntoNMut<'_, T>
+    {
+        assert!(n != 0, "Cannot split into zero parts.");
+        SplitIntoNMut {
+            rest: self,
+            n
+        }
+    }
+
+    fn split_by_ratio<'a>(&'a self, weights: &'a [usize]) -> SplitByRatio<'a, T>
+    {
+        SplitByRatio {
+            len: self.len(),
+            rest: self,
+            weights,
+            total: weight_sum(weights),
+            acc: 0
+        }
+    }
+    fn split_by_ratio_mut<'a>(&'a mut self, weights: &'a [usize]) -> SplitByRatioMut<'a, T>
+    {
+        SplitByRatioMut {
+            len: self.len(),
+            rest: self,
+            weights,
+            total: weight_sum(weights),
+            acc: 0
+        }
+    }
+}
+
+const fn check_mids(mids: &[usize], len: usize)
+{
+    let mut prev = 0;
+    let mut i = 0;
+    while i < mids.len()
+    {
+        assert!(mids[i] >= prev, "Split points must be sorted.");
+        prev = mids[i];
+        i += 1;
+    }
+    assert!(prev <= len, "Split point out of bounds.");
+}
+
+const fn weight_sum(weights: &[usize]) -> usize
+{
+    let mut total: usize = 0;
+    let mut i = 0;
+    while i < weights.len()
+    {
+        total = match total.checked_add(weights[i])
+        {
+            Some(total) => total,
+            None => panic!("Sum of weights overflowed.")
+        };
+        i += 1;
+    }
+    assert!(total != 0, "Weights must not sum to zero.");
+    total
+}
+
+/// The length of the next part of a [`split_by_ratio`](SliceSplit::split_by_ratio), and the updated weight accumulator.
+fn ratio_step(weights: &mut &[usize], acc: usize, total: usize, len: usize, rest: usize) -> Option<(usize, usize)>
+{
+    let (&w, tail) = weights.split_first()?;
+    *weights = tail;
+    let acc = acc + w;
+    let end = (len as u128*acc as u128/total as u128) as usize;
+    Some((rest - (len - end), acc))
+}
+
+/// An iterator over the parts of a slice split at many indices.
+/// 
+/// Returned by [`split_at_many`](SliceSplit::split_at_many).
+#[derive(Clone, Debug)]
+pub struct SplitAtMany<'a, T>
+{
+    rest: &'a [T],
+    mids: &'a [usize],
+    offset: usize,
+    finished: bool
+}
+
+impl<'a, T> Iterator for SplitAtMany<'a, T>
+{
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.finished
+        {
+            return None
+        }
+        match self.mids.split_first()
+        {
+            Some((&mid, tail)) => {
+                let (part, rest) = self.rest.split_at(mid - self.offset);
+                self.rest = rest;
+                self.mids = tail;
+                self.offset = mid;
+                Some(part)
+            },
+            None => {
+                self.finished = true;
+                Some(self.rest)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let n = if self.finished {0} else {self.mids.len() + 1};
+        (n, Some(n))
+    }
+}
+impl<'a, T> ExactSizeIterator for SplitAtMany<'a, T> {}
+impl<'a, T> FusedIterator for SplitAtMany<'a, T> {}
+
+/// An iterator over the disjoint mutable parts of a slice split at many indices.
+/// 
+/// Returned by [`split_at_many_mut`](SliceSplit::split_at_many_mut).
+#[derive(Debug)]
+pub struct SplitAtManyMut<'a, T>
+{
+    rest: &'a mut [T],
+    mids: &'a [usize],
+    offset: usize,
+    finished: bool
+}
+
+impl<'a, T> Iterator for SplitAtManyMut<'a, T>
+{
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.finished
+        {
+            return None
+        }
+        let rest = core::mem::take(&mut self.rest);
+        match self.mids.split_first()
+        {
+            Some((&mid, tail)) => {
+                let (part, rest) = rest.split_at_mut(mid - self.offset);
+                self.rest = rest;
+                self.mids = tail;
+                self.offset = mid;
+                Some(part)
+            },
+            None => {
+                self.finished = true;
+                Some(rest)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let n = if self.finished {0} else {self.mids.len() + 1};
+        (n, Some(n))
+    }
+}
+impl<'a, T> ExactSizeIterator for SplitAtManyMut<'a, T> {}
+impl<'a, T> FusedIterator for SplitAtManyMut<'a, T> {}
+
+/// An iterator over `n` balanced parts of a slice.
+/// 
+/// Returned by [`split_into_n`](SliceSplit::split_into_n).
+#[derive(Clone, Debug)]
+pub struct SplitIntoN<'a, T>
+{
+    rest: &'a [T],
+    n: usize
 }
 
+impl<'a, T> Iterator for SplitIntoN<'a, T>
+{
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.n == 0
+        {
+            return None
+        }
+        let (part, rest) = self.rest.split_at(self.rest.len().div_ceil(self.n));
+        self.rest = rest;
+        self.n -= 1;
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.n, Some(self.n))
+    }
+}
+impl<'a, T> ExactSizeIterator for SplitIntoN<'a, T> {}
+impl<'a, T> FusedIterator for SplitIntoN<'a, T> {}
+
+/// An iterator over `n` balanced disjoint mutable parts of a slice.
+/// 
+/// Returned by [`split_into_n_mut`](SliceSplit::split_into_n_mut).
+#[derive(Debug)]
+pub struct SplitIntoNMut<'a, T>
+{
+    rest: &'a mut [T],
+    n: usize
+}
+
+impl<'a, T> Iterator for SplitIntoNMut<'a, T>
+{
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.n == 0
+        {
+            return None
+        }
+        let rest = core::mem::take(&mut self.rest);
+        let (part, rest) = rest.split_at_mut(rest.len().div_ceil(self.n));
+        self.rest = rest;
+        self.n -= 1;
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.n, Some(self.n))
+    }
+}
+impl<'a, T> ExactSizeIterator for SplitIntoNMut<'a, T> {}
+impl<'a, T> FusedIterator for SplitIntoNMut<'a, T> {}
+
+/// An iterator over the parts of a slice split by weights.
+/// 
+/// Returned by [`split_by_ratio`](SliceSplit::split_by_ratio).
+#[derive(Clone, Debug)]
+pub struct SplitByRatio<'a, T>
+{
+    rest: &'a [T],
+    weights: &'a [usize],
+    len: usize,
+    total: usize,
+    acc: usize
+}
+
+impl<'a, T> Iterator for SplitByRatio<'a, T>
+{
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let (mid, acc) = ratio_step(&mut self.weights, self.acc, self.total, self.len, self.rest.len())?;
+        let (part, rest) = self.rest.split_at(mid);
+        self.rest = rest;
+        self.acc = acc;
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.weights.len(), Some(self.weights.len()))
+    }
+}
+impl<'a, T> ExactSizeIterator for SplitByRatio<'a, T> {}
+impl<'a, T> FusedIterator for SplitByRatio<'a, T> {}
+
+/// An iterator over the disjoint mutable parts of a slice split by weights.
+/// 
+/// Returned by [`split_by_ratio_mut`](SliceSplit::split_by_ratio_mut).
+#[derive(Debug)]
+pub struct SplitByRatioMut<'a, T>
+{
+    rest: &'a mut [T],
+    weights: &'a [usize],
+    len: usize,
+    total: usize,
+    acc: usize
+}
+
+impl<'a, T> Iterator for SplitByRatioMut<'a, T>
+{
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let (mid, acc) = ratio_step(&mut self.weights, self.acc, self.total, self.len, self.rest.len())?;
+        let (part, rest) = core::mem::take(&mut self.rest).split_at_mut(mid);
+        self.rest = rest;
+        self.acc = acc;
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.weights.len(), Some(self.weights.len()))
+    }
+}
+impl<'a, T> ExactSizeIterator for SplitByRatioMut<'a, T> {}
+impl<'a, T> FusedIterator for SplitByRatioMut<'a, T> {}
+
 #[cfg(test)]
 mod test
 {
+    use crate::{error::Error, ops::SliceSplit};
+
     #[test]
     fn it_works()
     {
         
     }
+
+    #[test]
+    fn try_rsplit_at()
+    {
+        let values = [1, 2, 3, 4];
+
+        for mid in 0..=4
+        {
+            assert_eq!(values.try_rsplit_at(mid), Ok(values.rsplit_at(mid)));
+        }
+        assert_eq!(values.try_rsplit_at(5), Err(Error::SplitOutOfBounds {mid: 5, len: 4}));
+    }
+
+    #[test]
+    fn split_array()
+    {
+        let mut values = [1, 2, 3, 4, 5, 6, 7];
+
+        assert_eq!(values.split_array_ref::<0>(), Some((&[], &values[..])));
+        assert_eq!(values.rsplit_array_ref::<7>(), Some((&[][..], &values)));
+        assert_eq!(values.rsplit_array_mut::<8>(), None);
+
+        let (chunks, rest) = values.split_array_chunks_mut::<2>();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(rest, &mut [7]);
+        chunks.reverse();
+        assert_eq!(values, [5, 6, 3, 4, 1, 2, 7]);
+
+        let (chunks, rest) = values.split_array_chunks::<8>();
+        assert!(chunks.is_empty());
+        assert_eq!(rest, &values);
+    }
+
+    #[test]
+    fn split_many()
+    {
+        let mut values: [usize; 11] = core::array::from_fn(|i| i);
+
+        let parts: Vec<_> = values.split_at_many(&[0, 3, 11]).collect();
+        assert_eq!(parts, [&[][..], &[0, 1, 2], &[3, 4, 5, 6, 7, 8, 9, 10], &[]]);
+
+        for n in 1..=13
+        {
+            let lens: Vec<_> = values.split_into_n_mut(n).map(|part| part.len()).collect();
+            assert_eq!(lens.len(), n);
+            assert_eq!(lens.iter().sum::<usize>(), 11);
+            assert!(lens.windows(2).all(|w| w[0] >= w[1] && w[0] - w[1] <= 1));
+        }
+
+        let lens: Vec<_> = values.split_by_ratio_mut(&[1, 1, 1]).map(|part| part.len()).collect();
+        assert_eq!(lens, [3, 4, 4]);
+        let parts: Vec<_> = values.split_by_ratio(&[0, 5]).collect();
+        assert_eq!(parts, [&[][..], &values[..]]);
+    }
 }
\ No newline at end of file
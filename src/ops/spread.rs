@@ -2,13 +2,17 @@ use core::mem::MaybeUninit;
 
 use slice_trait::Slice;
 
-use crate::padded::Padded;
+use crate::{error::Error, strided::{SpreadDyn, SpreadDynMut, Strided, StridedMut}};
 
 #[const_trait]
 pub trait SliceSpread<T>: Slice<Item = T>
 {
-    /// Spreads elements equally across `M` slices.
-    /// Slices will have equal length only if the operand slice's length is divisible by `M`.
+    /// Spreads elements equally across `M` strided views.
+    /// Views will have equal length only if the operand slice's length is divisible by `M`.
+    /// 
+    /// Each view only ever references elements of the operand slice, so this is sound for any length.
+    /// 
+    /// Before version 2.0.0, this returned `&[Padded<T, M>]`, which referenced memory past the end of the slice when its length was not divisible by `M`.
     /// 
     /// # Example
     /// 
@@ -27,12 +31,14 @@ pub trait SliceSpread<T>: Slice<Item = T>
     /// assert_eq!(odd, [1, 3]);
     /// assert_eq!(even, [2]);
     /// ```
-    fn spread<const M: usize>(&self) -> [&[Padded<T, M>]; M]
+    fn spread<const M: usize>(&self) -> [Strided<'_, T>; M]
     where
         [(); M - 1]:;
     
-    /// Spreads elements equally across `M` mutable slices.
-    /// Slices will have equal length only if the operand slice's length is divisible by `M`.
+    /// Spreads elements equally across `M` disjoint mutable strided views.
+    /// Views will have equal length only if the operand slice's length is divisible by `M`.
+    /// 
+    /// Before version 2.0.0, this returned `&mut [Padded<T, M>]`, whose lanes aliased each other and the memory past the end of the slice.
     /// 
     /// # Example
     /// ```rust
@@ -43,49 +49,181 @@ pub trait SliceSpread<T>: Slice<Item = T>
     /// let mut arr = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"];
     /// let slice = arr.as_mut_slice();
     /// 
-    /// let [_, _, fizz] = slice.spread_mut::<3>();
+    /// let [_, _, mut fizz] = slice.spread_mut::<3>();
     /// assert_eq!(fizz, ["3", "6", "9", "12", "15"]);
     /// for fizz in fizz.iter_mut()
     /// {
-    ///     **fizz = "fizz";
+    ///     *fizz = "fizz";
     /// }
     /// 
-    /// let [_, _, _, _, buzz] = slice.spread_mut::<5>();
+    /// let [_, _, _, _, mut buzz] = slice.spread_mut::<5>();
     /// assert_eq!(buzz, ["5", "10", "fizz"]);
     /// for buzz in buzz.iter_mut()
     /// {
-    ///     if **buzz == "fizz"
+    ///     if *buzz == "fizz"
     ///     {
-    ///         **buzz = "fizzbuzz";
+    ///         *buzz = "fizzbuzz";
     ///         continue;
     ///     }
-    ///     **buzz = "buzz";
+    ///     *buzz = "buzz";
     /// }
     /// 
     /// assert_eq!(arr, ["1", "2", "fizz", "4", "buzz", "fizz", "7", "8", "fizz", "buzz", "11", "fizz", "13", "14", "fizzbuzz"]);
     /// ```
-    fn spread_mut<const M: usize>(&mut self) -> [&mut [Padded<T, M>]; M]
+    fn spread_mut<const M: usize>(&mut self) -> [StridedMut<'_, T>; M]
     where
         [(); M - 1]:;
+
+    /// Spreads elements equally across `m` strided views, where `m` may be chosen at runtime.
+    /// Views will have equal length only if the operand slice's length is divisible by `m`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `m` is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let arr = [1, 2, 3, 4, 5, 6, 7];
+    /// let channels = 3;
+    /// 
+    /// let lanes: Vec<_> = arr.spread_dyn(channels).collect();
+    /// 
+    /// assert_eq!(lanes[0], [1, 4, 7]);
+    /// assert_eq!(lanes[1], [2, 5]);
+    /// assert_eq!(lanes[2], [3, 6]);
+    /// ```
+    fn spread_dyn(&self, m: usize) -> SpreadDyn<'_, T>;
+    
+    /// Spreads elements equally across `m` disjoint mutable strided views, where `m` may be chosen at runtime.
+    /// Views will have equal length only if the operand slice's length is divisible by `m`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `m` is zero.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let channels = 2;
+    /// 
+    /// for (gain, mut lane) in [10, 100].into_iter().zip(arr.spread_dyn_mut(channels))
+    /// {
+    ///     lane.mul_assign_all(gain);
+    /// }
+    /// 
+    /// assert_eq!(arr, [10, 200, 30, 400, 50, 600, 70, 800]);
+    /// ```
+    fn spread_dyn_mut(&mut self, m: usize) -> SpreadDynMut<'_, T>;
+
+    /// Writes `M` lanes back into the slice, interleaving them. This is the inverse of [`spread`](SliceSpread::spread).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the lanes do not have the lengths that [`spread`](SliceSpread::spread) would produce for this slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = [0; 5];
+    /// 
+    /// arr.unspread_from([&[1, 3, 5], &[2, 4]]);
+    /// 
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    fn unspread_from<const M: usize>(&mut self, lanes: [&[T]; M])
+    where
+        T: Clone,
+        [(); M - 1]:;
+
+    /// Writes `m` lanes back into the slice, interleaving them, where `m` may be chosen at runtime.
+    /// This is the inverse of [`spread_dyn`](SliceSpread::spread_dyn).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if there are no lanes, or if the lanes do not have the lengths that [`spread_dyn`](SliceSpread::spread_dyn) would produce for this slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut arr = [0; 7];
+    /// let lanes: [&[i32]; 3] = [&[1, 4, 7], &[2, 5], &[3, 6]];
+    /// 
+    /// arr.unspread_from_dyn(&lanes);
+    /// 
+    /// assert_eq!(arr, [1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    fn unspread_from_dyn(&mut self, lanes: &[&[T]])
+    where
+        T: Clone;
+
+    /// Writes `M` lanes back into the slice, interleaving them, or returns an error if the lanes do not have the lengths that [`spread`](SliceSpread::spread) would produce for this slice.
+    /// 
+    /// The slice is left untouched if an error is returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::{ops::*, error::Error};
+    /// 
+    /// let mut arr = [0; 4];
+    /// 
+    /// assert_eq!(arr.try_unspread_from([&[1, 3, 5], &[2]]), Err(Error::LaneLengthMismatch {lane: 0, len: 3, expected: 2}));
+    /// assert_eq!(arr.try_unspread_from([&[1, 3], &[2, 4]]), Ok(()));
+    /// assert_eq!(arr, [1, 2, 3, 4]);
+    /// ```
+    fn try_unspread_from<const M: usize>(&mut self, lanes: [&[T]; M]) -> Result<(), Error>
+    where
+        T: Clone,
+        [(); M - 1]:;
+
+    /// Writes `m` lanes back into the slice, interleaving them, or returns an error if the lanes do not have the lengths that [`spread_dyn`](SliceSpread::spread_dyn) would produce for this slice.
+    /// 
+    /// Returns [`Error::NoLanes`] if `lanes` is empty. The slice is left untouched if an error is returned.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::{ops::*, error::Error};
+    /// 
+    /// let mut arr = [0; 4];
+    /// let lanes: [&[i32]; 3] = [&[1, 4], &[2], &[3, 5]];
+    /// 
+    /// assert_eq!(arr.try_unspread_from_dyn(&lanes), Err(Error::LaneLengthMismatch {lane: 2, len: 2, expected: 1}));
+    /// assert_eq!(arr, [0; 4]);
+    /// ```
+    fn try_unspread_from_dyn(&mut self, lanes: &[&[T]]) -> Result<(), Error>
+    where
+        T: Clone;
 }
 
 impl<T> SliceSpread<T> for [T]
 {   
-    fn spread<const M: usize>(&self) -> [&[Padded<T, M>]; M]
+    fn spread<const M: usize>(&self) -> [Strided<'_, T>; M]
     where
         [(); M - 1]:
     {
-        let len = self.len();
-        let ptr = self.as_ptr();
+        let lanes = SpreadDyn::new(self, M);
     
-        let mut spread: [MaybeUninit<&[Padded<T, M>]>; M] = MaybeUninit::uninit_array();
+        let mut spread: [MaybeUninit<Strided<'_, T>>; M] = MaybeUninit::uninit_array();
             
         let mut i = 0;
         while i < M
         {
-            spread[i].write(unsafe {
-                core::slice::from_raw_parts(ptr.add(i).cast(), len/M + if len % M > i {1} else {0})
-            });
+            spread[i].write(lanes.lane(i));
             i += 1;
         }
 
@@ -93,20 +231,20 @@ impl<T> SliceSpread<T> for [T]
             MaybeUninit::array_assume_init(spread)
         }
     }
-    fn spread_mut<const M: usize>(&mut self) -> [&mut [Padded<T, M>]; M]
+    fn spread_mut<const M: usize>(&mut self) -> [StridedMut<'_, T>; M]
     where
         [(); M - 1]:
     {
-        let len = self.len();
-        let ptr = self.as_mut_ptr();
+        let lanes = SpreadDynMut::new(self, M);
     
-        let mut spread: [MaybeUninit<&mut [Padded<T, M>]>; M] = MaybeUninit::uninit_array();
+        let mut spread: [MaybeUninit<StridedMut<'_, T>>; M] = MaybeUninit::uninit_array();
             
         let mut i = 0;
         while i < M
         {
+            // Each lane is only taken once, so the views are disjoint.
             spread[i].write(unsafe {
-                core::slice::from_raw_parts_mut(ptr.add(i).cast(), len/M + if len % M > i {1} else {0})
+                lanes.lane(i)
             });
             i += 1;
         }
@@ -115,12 +253,86 @@ impl<T> SliceSpread<T> for [T]
             MaybeUninit::array_assume_init(spread)
         }
     }
+    fn spread_dyn(&self, m: usize) -> SpreadDyn<'_, T>
+    {
+        SpreadDyn::new(self, m)
+    }
+    fn spread_dyn_mut(&mut self, m: usize) -> SpreadDynMut<'_, T>
+    {
+        SpreadDynMut::new(self, m)
+    }
+
+    fn unspread_from<const M: usize>(&mut self, lanes: [&[T]; M])
+    where
+        T: Clone,
+        [(); M - 1]:
+    {
+        self.unspread_from_dyn(&lanes)
+    }
+    fn unspread_from_dyn(&mut self, lanes: &[&[T]])
+    where
+        T: Clone
+    {
+        if let Err(error) = self.try_unspread_from_dyn(lanes)
+        {
+            panic!("Lanes do not match the spread of the slice: {error}.")
+        }
+    }
+    fn try_unspread_from<const M: usize>(&mut self, lanes: [&[T]; M]) -> Result<(), Error>
+    where
+        T: Clone,
+        [(); M - 1]:
+    {
+        self.try_unspread_from_dyn(&lanes)
+    }
+    fn try_unspread_from_dyn(&mut self, lanes: &[&[T]]) -> Result<(), Error>
+    where
+        T: Clone
+    {
+        let m = lanes.len();
+        if m == 0
+        {
+            return Err(Error::NoLanes)
+        }
+
+        let len = self.len();
+        let mut i = 0;
+        while i < m
+        {
+            let expected = len/m + if len % m > i {1} else {0};
+            if lanes[i].len() != expected
+            {
+                return Err(Error::LaneLengthMismatch {
+                    lane: i,
+                    len: lanes[i].len(),
+                    expected
+                })
+            }
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i < m
+        {
+            let lane = lanes[i];
+            let l = lane.len();
+            let mut j = 0;
+            while j < l
+            {
+                self[i + j*m].clone_from(&lane[j]);
+                j += 1;
+            }
+            i += 1;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test
 {
-    use crate::ops::SliceSpread;
+    use crate::{error::Error, ops::SliceSpread};
 
     #[test]
     fn fizzbuzz()
@@ -128,23 +340,23 @@ mod test
         let mut arr = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15"];
         let slice = arr.as_mut_slice();
 
-        let [_, _, fizz] = slice.spread_mut();
+        let [_, _, mut fizz] = slice.spread_mut();
         assert_eq!(fizz, ["3", "6", "9", "12", "15"]);
         for fizz in fizz.iter_mut()
         {
-            **fizz = "fizz";
+            *fizz = "fizz";
         }
 
-        let [_, _, _, _, buzz] = slice.spread_mut();
+        let [_, _, _, _, mut buzz] = slice.spread_mut();
         assert_eq!(buzz, ["5", "10", "fizz"]);
         for buzz in buzz.iter_mut()
         {
-            if **buzz == "fizz"
+            if *buzz == "fizz"
             {
-                **buzz = "fizzbuzz";
+                *buzz = "fizzbuzz";
                 continue;
             }
-            **buzz = "buzz";
+            *buzz = "buzz";
         }
 
         assert_eq!(
@@ -152,4 +364,69 @@ mod test
             ["1", "2", "fizz", "4", "buzz", "fizz", "7", "8", "fizz", "buzz", "11", "fizz", "13", "14", "fizzbuzz"]
         );
     }
-}
\ No newline at end of file
+
+    fn check_lanes<const M: usize>()
+    where
+        [(); M - 1]:
+    {
+        for len in 0..4*M
+        {
+            // Boxed with exact capacity, so that any access past the end is caught by Miri.
+            let mut data: Box<[usize]> = (0..len).collect();
+
+            for (i, lane) in data.spread::<M>().into_iter().enumerate()
+            {
+                assert_eq!(lane.len(), (i..len).step_by(M).len());
+                assert!(lane.iter().copied().eq((i..len).step_by(M)));
+            }
+
+            let mut lanes = data.spread_mut::<M>();
+            let mut j = 0;
+            while j < len/M + 1
+            {
+                for lane in lanes.iter_mut()
+                {
+                    if let Some(x) = lane.get_mut(j)
+                    {
+                        *x = !*x;
+                    }
+                }
+                j += 1;
+            }
+            assert!(data.iter().copied().eq((0..len).map(|x| !x)));
+        }
+    }
+
+    #[test]
+    fn all_lengths_and_lanes()
+    {
+        check_lanes::<1>();
+        check_lanes::<2>();
+        check_lanes::<3>();
+        check_lanes::<4>();
+        check_lanes::<5>();
+        check_lanes::<6>();
+        check_lanes::<7>();
+        check_lanes::<8>();
+    }
+
+    #[test]
+    fn unspread()
+    {
+        let mut arr: Vec<usize> = (0..17).collect();
+        let lanes: Vec<Vec<usize>> = arr.spread_dyn(5)
+            .map(|lane| lane.iter().map(|&x| x*2).collect())
+            .collect();
+        let lanes: Vec<&[usize]> = lanes.iter().map(|lane| lane.as_slice()).collect();
+
+        arr.unspread_from_dyn(&lanes);
+        assert!(arr.iter().enumerate().all(|(i, &x)| x == 2*i));
+
+        assert!(arr.try_unspread_from([&[0; 9], &[0; 9]]).is_err());
+        assert!(arr.try_unspread_from([&[0; 9], &[0; 8]]).is_ok());
+        assert!(arr.iter().all(|&x| x == 0));
+
+        assert_eq!(arr.try_unspread_from_dyn(&[]), Err(Error::NoLanes));
+        assert_eq!([0u8; 0].try_unspread_from_dyn(&[]), Err(Error::NoLanes));
+    }
+}
@@ -0,0 +1,184 @@
+use slice_trait::Slice;
+
+/// Side length of the square blocks used by [`transpose_into`](SliceTranspose::transpose_into).
+const BLOCK: usize = 16;
+
+#[const_trait]
+pub trait SliceTranspose<T>: Slice<Item = T>
+{
+    /// Transposes a row-major matrix with the shape `(rows, cols)` in-place, such that it becomes a row-major matrix with the shape `(cols, rows)`.
+    /// 
+    /// Square matrices are transposed by swapping elements across the diagonal.
+    /// Rectangular matrices are transposed by following the cycles of the permutation, without any extra space.
+    /// Each cycle is only rotated from its smallest index, and finding out whether an index is the smallest in its cycle means walking that cycle,
+    /// so this takes `O(n²)` steps in the worst case, where `n = rows*cols`. If extra space is available, [`transpose_into`](SliceTranspose::transpose_into) is faster.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the length of the slice is not `rows*cols`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut matrix = [
+    ///     1, 2, 3,
+    ///     4, 5, 6
+    /// ];
+    /// 
+    /// matrix.transpose(2, 3);
+    /// 
+    /// assert_eq!(matrix, [
+    ///     1, 4,
+    ///     2, 5,
+    ///     3, 6
+    /// ]);
+    /// ```
+    fn transpose(&mut self, rows: usize, cols: usize);
+
+    /// Writes the transpose of a row-major matrix with the shape `(rows, cols)` into `out`, which becomes a row-major matrix with the shape `(cols, rows)`.
+    /// 
+    /// The matrix is traversed in blocks, to make better use of the cache for large matrices.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the length of either slice is not `rows*cols`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let matrix = [
+    ///     1, 2, 3,
+    ///     4, 5, 6
+    /// ];
+    /// let mut transposed = [0; 6];
+    /// 
+    /// matrix.transpose_into(2, 3, &mut transposed);
+    /// 
+    /// assert_eq!(transposed, [
+    ///     1, 4,
+    ///     2, 5,
+    ///     3, 6
+    /// ]);
+    /// ```
+    fn transpose_into(&self, rows: usize, cols: usize, out: &mut [T])
+    where
+        T: Clone;
+}
+
+impl<T> SliceTranspose<T> for [T]
+{
+    fn transpose(&mut self, rows: usize, cols: usize)
+    {
+        let len = self.len();
+        assert_eq!(rows*cols, len, "Length must be rows*cols.");
+
+        if rows == cols
+        {
+            let mut i = 0;
+            while i < rows
+            {
+                let mut j = i + 1;
+                while j < cols
+                {
+                    self.swap(i*cols + j, j*rows + i);
+                    j += 1;
+                }
+                i += 1;
+            }
+            return
+        }
+        if rows <= 1 || cols <= 1
+        {
+            return
+        }
+
+        // The element in row `k / cols` and column `k % cols` moves to row `k % cols` and column `k / cols`.
+        // This equals `k*rows mod (len - 1)`, but never overflows.
+        let dest = |k: usize| (k % cols)*rows + k / cols;
+
+        let mut start = 1;
+        while start < len - 1
+        {
+            // Only rotate each cycle once, from its smallest index.
+            let mut j = dest(start);
+            while j > start
+            {
+                j = dest(j);
+            }
+            if j == start
+            {
+                let mut j = dest(start);
+                while j != start
+                {
+                    self.swap(start, j);
+                    j = dest(j);
+                }
+            }
+            start += 1;
+        }
+    }
+
+    fn transpose_into(&self, rows: usize, cols: usize, out: &mut [T])
+    where
+        T: Clone
+    {
+        assert_eq!(rows*cols, self.len(), "Length must be rows*cols.");
+        assert_eq!(rows*cols, out.len(), "Length must be rows*cols.");
+
+        let mut i0 = 0;
+        while i0 < rows
+        {
+            let i1 = (i0 + BLOCK).min(rows);
+            let mut j0 = 0;
+            while j0 < cols
+            {
+                let j1 = (j0 + BLOCK).min(cols);
+                let mut i = i0;
+                while i < i1
+                {
+                    let mut j = j0;
+                    while j < j1
+                    {
+                        out[j*rows + i] = self[i*cols + j].clone();
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                j0 = j1;
+            }
+            i0 = i1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::SliceTranspose;
+
+    #[test]
+    fn it_works()
+    {
+        for rows in 0..20
+        {
+            for cols in 0..20
+            {
+                let matrix: Vec<usize> = (0..rows*cols).collect();
+
+                let mut expected = vec![0; rows*cols];
+                matrix.transpose_into(rows, cols, &mut expected);
+
+                let mut transposed = matrix.clone();
+                transposed.transpose(rows, cols);
+                assert_eq!(transposed, expected);
+
+                transposed.transpose(cols, rows);
+                assert_eq!(transposed, matrix);
+            }
+        }
+    }
+}
@@ -1,5 +1,10 @@
+use core::ops::Range;
+
 use slice_trait::Slice;
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
 #[const_trait]
 pub trait SliceTrim<T>: Slice<Item = T>
 {
@@ -111,6 +116,182 @@ pub trait SliceTrim<T>: Slice<Item = T>
     fn trim_back_mut<F>(&mut self, trim: F) -> &mut [T]
     where
         F: FnMut(&T) -> bool /*+ ~const Destruct*/;
+
+    /// Returns the range of indices that [`trim`](SliceTrim::trim) would keep.
+    /// 
+    /// `trim` should return `true` for each element that should be trimmed.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let a = [0, 0, 0, 1, 2, 3, 0, 0];
+    /// 
+    /// let range = a.trim_range(|&e| e == 0);
+    /// 
+    /// assert_eq!(range, 3..6);
+    /// assert_eq!(a[range], [1, 2, 3]);
+    /// ```
+    fn trim_range<F>(&self, trim: F) -> Range<usize>
+    where
+        F: FnMut(&T) -> bool /*+ ~const Destruct*/;
+    /// Returns a trimmed subslice, trimmed from both ends using a trimming predicate, but with at most `n` elements removed from each end.
+    /// 
+    /// `trim` should return `true` for each element that should be trimmed.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let a = [0, 0, 0, 1, 2, 3, 0];
+    /// 
+    /// let at = a.trim_at_most(2, |&e| e == 0);
+    /// 
+    /// assert_eq!(at, &[0, 1, 2, 3]);
+    /// ```
+    fn trim_at_most<F>(&self, n: usize, trim: F) -> &[T]
+    where
+        F: FnMut(&T) -> bool /*+ ~const Destruct*/;
+    /// Returns a mutable trimmed subslice, trimmed from both ends using a trimming predicate, but with at most `n` elements removed from each end.
+    /// 
+    /// `trim` should return `true` for each element that should be trimmed.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut a = [0, 0, 0, 1, 2, 3, 0];
+    /// 
+    /// let at = a.trim_at_most_mut(2, |&e| e == 0);
+    /// 
+    /// assert_eq!(at, &mut [0, 1, 2, 3]);
+    /// ```
+    fn trim_at_most_mut<F>(&mut self, n: usize, trim: F) -> &mut [T]
+    where
+        F: FnMut(&T) -> bool /*+ ~const Destruct*/;
+    /// Returns a subslice with all elements equal to `x` trimmed from both ends.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let a = [0, 0, 1, 0, 3, 0];
+    /// 
+    /// assert_eq!(a.trim_matches(&0), &[1, 0, 3]);
+    /// ```
+    fn trim_matches(&self, x: &T) -> &[T]
+    where
+        T: PartialEq;
+    /// Returns a mutable subslice with all elements equal to `x` trimmed from both ends.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut a = [0, 0, 1, 0, 3, 0];
+    /// 
+    /// assert_eq!(a.trim_matches_mut(&0), &mut [1, 0, 3]);
+    /// ```
+    fn trim_matches_mut(&mut self, x: &T) -> &mut [T]
+    where
+        T: PartialEq;
+    /// Returns a subslice with all elements equal to any of `xs` trimmed from both ends.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let a = [' ', '\t', 'h', 'i', '\n'];
+    /// 
+    /// assert_eq!(a.trim_matches_any(&[' ', '\t', '\n']), &['h', 'i']);
+    /// ```
+    fn trim_matches_any(&self, xs: &[T]) -> &[T]
+    where
+        T: PartialEq;
+    /// Returns a mutable subslice with all elements equal to any of `xs` trimmed from both ends.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut a = [' ', '\t', 'h', 'i', '\n'];
+    /// 
+    /// assert_eq!(a.trim_matches_any_mut(&[' ', '\t', '\n']), &mut ['h', 'i']);
+    /// ```
+    fn trim_matches_any_mut(&mut self, xs: &[T]) -> &mut [T]
+    where
+        T: PartialEq;
+    /// Returns a subslice with repeated occurrences of `prefix` stripped from the front.
+    /// 
+    /// If `prefix` is empty, the slice is returned as is.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let a = [1, 2, 1, 2, 1, 3];
+    /// 
+    /// assert_eq!(a.trim_prefix_slice(&[1, 2]), &[1, 3]);
+    /// ```
+    fn trim_prefix_slice(&self, prefix: &[T]) -> &[T]
+    where
+        T: PartialEq;
+    /// Returns a mutable subslice with repeated occurrences of `prefix` stripped from the front.
+    /// 
+    /// If `prefix` is empty, the slice is returned as is.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut a = [1, 2, 1, 2, 1, 3];
+    /// 
+    /// assert_eq!(a.trim_prefix_slice_mut(&[1, 2]), &mut [1, 3]);
+    /// ```
+    fn trim_prefix_slice_mut(&mut self, prefix: &[T]) -> &mut [T]
+    where
+        T: PartialEq;
+    /// Returns a subslice with repeated occurrences of `suffix` stripped from the back.
+    /// 
+    /// If `suffix` is empty, the slice is returned as is.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let a = [3, 1, 0, 0, 0, 0];
+    /// 
+    /// assert_eq!(a.trim_suffix_slice(&[0, 0]), &[3, 1]);
+    /// ```
+    fn trim_suffix_slice(&self, suffix: &[T]) -> &[T]
+    where
+        T: PartialEq;
+    /// Returns a mutable subslice with repeated occurrences of `suffix` stripped from the back.
+    /// 
+    /// If `suffix` is empty, the slice is returned as is.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut a = [3, 1, 0, 0, 0];
+    /// 
+    /// assert_eq!(a.trim_suffix_slice_mut(&[0, 0]), &mut [3, 1, 0]);
+    /// ```
+    fn trim_suffix_slice_mut(&mut self, suffix: &[T]) -> &mut [T]
+    where
+        T: PartialEq;
 }
 
 impl<T> SliceTrim<T> for [T]
@@ -195,6 +376,247 @@ impl<T> SliceTrim<T> for [T]
 
         slice
     }
+
+    fn trim_range<F>(&self, trim: F) -> Range<usize>
+    where
+        F: FnMut(&T) -> bool
+    {
+        trim_range_at_most(self, usize::MAX, trim)
+    }
+    fn trim_at_most<F>(&self, n: usize, trim: F) -> &[T]
+    where
+        F: FnMut(&T) -> bool
+    {
+        &self[trim_range_at_most(self, n, trim)]
+    }
+    fn trim_at_most_mut<F>(&mut self, n: usize, trim: F) -> &mut [T]
+    where
+        F: FnMut(&T) -> bool
+    {
+        let range = trim_range_at_most(self, n, trim);
+        &mut self[range]
+    }
+    fn trim_matches(&self, x: &T) -> &[T]
+    where
+        T: PartialEq
+    {
+        self.trim(|e| e == x)
+    }
+    fn trim_matches_mut(&mut self, x: &T) -> &mut [T]
+    where
+        T: PartialEq
+    {
+        self.trim_mut(|e| e == x)
+    }
+    fn trim_matches_any(&self, xs: &[T]) -> &[T]
+    where
+        T: PartialEq
+    {
+        self.trim(|e| xs.contains(e))
+    }
+    fn trim_matches_any_mut(&mut self, xs: &[T]) -> &mut [T]
+    where
+        T: PartialEq
+    {
+        self.trim_mut(|e| xs.contains(e))
+    }
+    fn trim_prefix_slice(&self, prefix: &[T]) -> &[T]
+    where
+        T: PartialEq
+    {
+        &self[trim_prefix_len(self, prefix)..]
+    }
+    fn trim_prefix_slice_mut(&mut self, prefix: &[T]) -> &mut [T]
+    where
+        T: PartialEq
+    {
+        let start = trim_prefix_len(self, prefix);
+        &mut self[start..]
+    }
+    fn trim_suffix_slice(&self, suffix: &[T]) -> &[T]
+    where
+        T: PartialEq
+    {
+        &self[..trim_suffix_end(self, suffix)]
+    }
+    fn trim_suffix_slice_mut(&mut self, suffix: &[T]) -> &mut [T]
+    where
+        T: PartialEq
+    {
+        let end = trim_suffix_end(self, suffix);
+        &mut self[..end]
+    }
+}
+
+fn trim_range_at_most<T, F>(slice: &[T], n: usize, mut trim: F) -> Range<usize>
+where
+    F: FnMut(&T) -> bool
+{
+    let len = slice.len();
+    let mut end = len;
+    while end > 0 && len - end < n && trim(&slice[end - 1])
+    {
+        end -= 1;
+    }
+    let mut start = 0;
+    while start < end && start < n && trim(&slice[start])
+    {
+        start += 1;
+    }
+    start..end
+}
+
+fn trim_prefix_len<T>(slice: &[T], prefix: &[T]) -> usize
+where
+    T: PartialEq
+{
+    let m = prefix.len();
+    if m == 0
+    {
+        return 0
+    }
+    let mut start = 0;
+    while slice[start..].starts_with(prefix)
+    {
+        start += m;
+    }
+    start
+}
+
+fn trim_suffix_end<T>(slice: &[T], suffix: &[T]) -> usize
+where
+    T: PartialEq
+{
+    let m = suffix.len();
+    if m == 0
+    {
+        return slice.len()
+    }
+    let mut end = slice.len();
+    while slice[..end].ends_with(suffix)
+    {
+        end -= m;
+    }
+    end
+}
+
+/// Trimming of owned containers, which drops the trimmed elements instead of just returning a shorter reborrow.
+/// 
+/// For [`Vec`], the remaining elements are shifted down in-place, and the capacity is kept.
+/// For [`Box<[T]>`](Box), the remaining elements are moved into a new allocation of the trimmed length.
+#[cfg(feature = "alloc")]
+pub trait VecTrim<T>
+{
+    /// Trims the container from both ends using a trimming predicate, dropping the trimmed elements.
+    /// 
+    /// `trim` should return `true` for each element that should be trimmed.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut v = vec![0, 0, 0, 1, 2, 3, 0, 0, 0];
+    /// 
+    /// v.trim_in_place(|&e| e == 0);
+    /// 
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    fn trim_in_place<F>(&mut self, trim: F)
+    where
+        F: FnMut(&T) -> bool;
+    /// Trims the container from the left using a trimming predicate, dropping the trimmed elements.
+    /// 
+    /// `trim` should return `true` for each element that should be trimmed.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut v = vec![0, 0, 0, 1, 2, 3, 0, 0, 0];
+    /// 
+    /// v.trim_front_in_place(|&e| e == 0);
+    /// 
+    /// assert_eq!(v, [1, 2, 3, 0, 0, 0]);
+    /// ```
+    fn trim_front_in_place<F>(&mut self, trim: F)
+    where
+        F: FnMut(&T) -> bool;
+    /// Trims the container from the right using a trimming predicate, dropping the trimmed elements.
+    /// 
+    /// `trim` should return `true` for each element that should be trimmed.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use slice_ops::ops::*;
+    /// 
+    /// let mut v = vec![0, 0, 0, 1, 2, 3, 0, 0, 0].into_boxed_slice();
+    /// 
+    /// v.trim_back_in_place(|&e| e == 0);
+    /// 
+    /// assert_eq!(*v, [0, 0, 0, 1, 2, 3]);
+    /// ```
+    fn trim_back_in_place<F>(&mut self, trim: F)
+    where
+        F: FnMut(&T) -> bool;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> VecTrim<T> for Vec<T>
+{
+    fn trim_in_place<F>(&mut self, trim: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let range = self.trim_range(trim);
+        self.truncate(range.end);
+        self.drain(..range.start);
+    }
+    fn trim_front_in_place<F>(&mut self, mut trim: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let start = self.len() - self.trim_front(&mut trim).len();
+        self.drain(..start);
+    }
+    fn trim_back_in_place<F>(&mut self, trim: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let end = self.trim_back(trim).len();
+        self.truncate(end);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> VecTrim<T> for Box<[T]>
+{
+    fn trim_in_place<F>(&mut self, trim: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let mut v = core::mem::take(self).into_vec();
+        v.trim_in_place(trim);
+        *self = v.into_boxed_slice()
+    }
+    fn trim_front_in_place<F>(&mut self, trim: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let mut v = core::mem::take(self).into_vec();
+        v.trim_front_in_place(trim);
+        *self = v.into_boxed_slice()
+    }
+    fn trim_back_in_place<F>(&mut self, trim: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        let mut v = core::mem::take(self).into_vec();
+        v.trim_back_in_place(trim);
+        *self = v.into_boxed_slice()
+    }
 }
 
 #[cfg(test)]
@@ -211,4 +633,40 @@ mod test
 
         assert_eq!(at, &[1, 2, 3]);
     }
+
+    #[test]
+    fn trim_range()
+    {
+        let a = [0, 0, 0, 0];
+
+        assert_eq!(a.trim_range(|&e| e == 0), 0..0);
+        assert_eq!(a.trim_at_most(1, |&e| e == 0), &[0, 0]);
+        assert_eq!(a.trim_at_most(3, |&e| e == 0), &[]);
+        assert_eq!(a.trim_prefix_slice(&[0, 0, 0]), &[0]);
+        assert_eq!(a.trim_suffix_slice(&[0, 0]), &[]);
+        assert_eq!(a.trim_prefix_slice(&[]), &a);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn trim_in_place()
+    {
+        use std::rc::Rc;
+
+        use crate::ops::VecTrim;
+
+        let rc = Rc::new(());
+        let mut v: Vec<_> = (0..9).map(|i| (i % 4 == 0, rc.clone())).collect();
+
+        v.trim_in_place(|(e, _)| *e);
+        assert_eq!(v.len(), 7);
+        assert_eq!(Rc::strong_count(&rc), 8);
+
+        let mut b = v.into_boxed_slice();
+        b.trim_front_in_place(|(e, _)| !*e);
+        assert_eq!(b.len(), 4);
+        b.trim_back_in_place(|_| true);
+        assert!(b.is_empty());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
 }
\ No newline at end of file
@@ -1,5 +1,13 @@
-use core::{mem::{MaybeUninit, ManuallyDrop}, borrow::{Borrow, BorrowMut}, ops::{Deref, DerefMut}};
+use core::{borrow::{Borrow, BorrowMut}, hash::{Hash, Hasher}, mem::{ManuallyDrop, MaybeUninit}, ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign}};
 
+/// A value padded to occupy the same space as `WIDTH` values of type `T`.
+/// 
+/// The padding is owned by the value, so a `Padded` must never be made by reinterpreting a slice
+/// whose remaining length is shorter than `WIDTH`. [`as_chunks`](Padded::as_chunks) views a slice as padded values safely.
+/// For strided access into a slice, see [`Strided`](crate::strided::Strided).
+/// 
+/// Arithmetic and bitwise operators are implemented between two padded values of the same type, and between a padded value and a
+/// plain value of its inner type. Shifts therefore take the shift amount as a `T` rather than any other integer type.
 #[repr(C)]
 pub struct Padded<T, const WIDTH: usize>
 where
@@ -89,6 +97,521 @@ where
     }
 }
 
+impl<T, const WIDTH: usize> Clone for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Clone
+{
+    fn clone(&self) -> Self
+    {
+        Self::new(self.value.clone())
+    }
+}
+impl<T, const WIDTH: usize> Copy for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Copy
+{
+
+}
+
+impl<T, const WIDTH: usize> Hash for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Hash
+{
+    fn hash<H: Hasher>(&self, state: &mut H)
+    {
+        self.value.hash(state)
+    }
+}
+
+impl<T, const WIDTH: usize> Default for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Default
+{
+    fn default() -> Self
+    {
+        Self::new(T::default())
+    }
+}
+
+impl<T, const WIDTH: usize> From<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:
+{
+    fn from(value: T) -> Self
+    {
+        Self::new(value)
+    }
+}
+
+impl<T, const WIDTH: usize> Add<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Add
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn add(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() + rhs)
+    }
+}
+impl<T, const WIDTH: usize> Add for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Add
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn add(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() + rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> AddAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: AddAssign
+{
+    fn add_assign(&mut self, rhs: T)
+    {
+        self.value += rhs
+    }
+}
+impl<T, const WIDTH: usize> AddAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: AddAssign
+{
+    fn add_assign(&mut self, rhs: Self)
+    {
+        self.value += rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> Sub<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Sub
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn sub(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() - rhs)
+    }
+}
+impl<T, const WIDTH: usize> Sub for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Sub
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn sub(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() - rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> SubAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: SubAssign
+{
+    fn sub_assign(&mut self, rhs: T)
+    {
+        self.value -= rhs
+    }
+}
+impl<T, const WIDTH: usize> SubAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: SubAssign
+{
+    fn sub_assign(&mut self, rhs: Self)
+    {
+        self.value -= rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> Mul<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Mul
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn mul(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() * rhs)
+    }
+}
+impl<T, const WIDTH: usize> Mul for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Mul
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn mul(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() * rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> MulAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: MulAssign
+{
+    fn mul_assign(&mut self, rhs: T)
+    {
+        self.value *= rhs
+    }
+}
+impl<T, const WIDTH: usize> MulAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: MulAssign
+{
+    fn mul_assign(&mut self, rhs: Self)
+    {
+        self.value *= rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> Div<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Div
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn div(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() / rhs)
+    }
+}
+impl<T, const WIDTH: usize> Div for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Div
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn div(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() / rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> DivAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: DivAssign
+{
+    fn div_assign(&mut self, rhs: T)
+    {
+        self.value /= rhs
+    }
+}
+impl<T, const WIDTH: usize> DivAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: DivAssign
+{
+    fn div_assign(&mut self, rhs: Self)
+    {
+        self.value /= rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> Rem<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Rem
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn rem(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() % rhs)
+    }
+}
+impl<T, const WIDTH: usize> Rem for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Rem
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn rem(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() % rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> RemAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: RemAssign
+{
+    fn rem_assign(&mut self, rhs: T)
+    {
+        self.value %= rhs
+    }
+}
+impl<T, const WIDTH: usize> RemAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: RemAssign
+{
+    fn rem_assign(&mut self, rhs: Self)
+    {
+        self.value %= rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> BitAnd<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitAnd
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn bitand(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() & rhs)
+    }
+}
+impl<T, const WIDTH: usize> BitAnd for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitAnd
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn bitand(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() & rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> BitAndAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitAndAssign
+{
+    fn bitand_assign(&mut self, rhs: T)
+    {
+        self.value &= rhs
+    }
+}
+impl<T, const WIDTH: usize> BitAndAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitAndAssign
+{
+    fn bitand_assign(&mut self, rhs: Self)
+    {
+        self.value &= rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> BitOr<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitOr
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn bitor(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() | rhs)
+    }
+}
+impl<T, const WIDTH: usize> BitOr for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitOr
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn bitor(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() | rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> BitOrAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitOrAssign
+{
+    fn bitor_assign(&mut self, rhs: T)
+    {
+        self.value |= rhs
+    }
+}
+impl<T, const WIDTH: usize> BitOrAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitOrAssign
+{
+    fn bitor_assign(&mut self, rhs: Self)
+    {
+        self.value |= rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> BitXor<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitXor
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn bitxor(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() ^ rhs)
+    }
+}
+impl<T, const WIDTH: usize> BitXor for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitXor
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() ^ rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> BitXorAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitXorAssign
+{
+    fn bitxor_assign(&mut self, rhs: T)
+    {
+        self.value ^= rhs
+    }
+}
+impl<T, const WIDTH: usize> BitXorAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: BitXorAssign
+{
+    fn bitxor_assign(&mut self, rhs: Self)
+    {
+        self.value ^= rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> Shl<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Shl
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn shl(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() << rhs)
+    }
+}
+impl<T, const WIDTH: usize> Shl for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Shl
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn shl(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() << rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> ShlAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: ShlAssign
+{
+    fn shl_assign(&mut self, rhs: T)
+    {
+        self.value <<= rhs
+    }
+}
+impl<T, const WIDTH: usize> ShlAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: ShlAssign
+{
+    fn shl_assign(&mut self, rhs: Self)
+    {
+        self.value <<= rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> Shr<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Shr
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn shr(self, rhs: T) -> Self::Output
+    {
+        Padded::new(self.into_inner() >> rhs)
+    }
+}
+impl<T, const WIDTH: usize> Shr for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Shr
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn shr(self, rhs: Self) -> Self::Output
+    {
+        Padded::new(self.into_inner() >> rhs.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> ShrAssign<T> for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: ShrAssign
+{
+    fn shr_assign(&mut self, rhs: T)
+    {
+        self.value >>= rhs
+    }
+}
+impl<T, const WIDTH: usize> ShrAssign for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: ShrAssign
+{
+    fn shr_assign(&mut self, rhs: Self)
+    {
+        self.value >>= rhs.into_inner()
+    }
+}
+impl<T, const WIDTH: usize> Neg for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Neg
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn neg(self) -> Self::Output
+    {
+        Padded::new(-self.into_inner())
+    }
+}
+impl<T, const WIDTH: usize> Not for Padded<T, WIDTH>
+where
+    [(); WIDTH - 1]:,
+    T: Not
+{
+    type Output = Padded<T::Output, WIDTH>;
+
+    fn not(self) -> Self::Output
+    {
+        Padded::new(!self.into_inner())
+    }
+}
+
 impl<T, const WIDTH: usize> Padded<T, WIDTH>
 where
     [(); WIDTH - 1]:
@@ -115,6 +638,174 @@ where
     {
         &mut self.value
     }
+
+    /// Views a slice as a slice of padded values, each covering `WIDTH` consecutive elements, followed by the remainder
+    /// that is too short to fill a whole padded value.
+    /// 
+    /// Only the first element of each chunk is reachable through the padded value. There is no mutable counterpart,
+    /// since assigning a whole padded value would overwrite the rest of its chunk with uninitialized padding.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let x = [1, 2, 3, 4, 5, 6, 7];
+    /// 
+    /// let (padded, rest) = Padded::<_, 3>::as_chunks(&x);
+    /// 
+    /// assert_eq!(padded, [1, 4]);
+    /// assert_eq!(rest, [7]);
+    /// ```
+    pub const fn as_chunks(slice: &[T]) -> (&[Self], &[T])
+    {
+        let n = slice.len()/WIDTH;
+        let (chunks, rest) = slice.split_at(n*WIDTH);
+        let padded = unsafe {
+            core::slice::from_raw_parts(chunks.as_ptr().cast(), n)
+        };
+        (padded, rest)
+    }
+
+    /// Returns an iterator over the values in a slice of padded values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let x = [1, 0, 2, 0, 3, 0];
+    /// let (padded, _) = Padded::<_, 2>::as_chunks(&x);
+    /// 
+    /// assert!(Padded::iter_values(padded).eq(&[1, 2, 3]));
+    /// ```
+    pub fn iter_values(slice: &[Self]) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator
+    {
+        slice.iter()
+            .map(Self::borrow)
+    }
+
+    /// Returns an iterator over mutable references to the values in a slice of padded values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let mut padded: [Padded<i32, 2>; 3] = [1, 2, 3].map(Padded::from);
+    /// 
+    /// for x in Padded::iter_values_mut(&mut padded)
+    /// {
+    ///     *x *= 10;
+    /// }
+    /// 
+    /// assert_eq!(padded, [10, 20, 30]);
+    /// ```
+    pub fn iter_values_mut(slice: &mut [Self]) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator
+    {
+        slice.iter_mut()
+            .map(Self::borrow_mut)
+    }
+
+    /// Copies the values in a slice of padded values into a contiguous slice.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the two slices have different lengths.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let x = [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0];
+    /// let (padded, _) = Padded::<_, 4>::as_chunks(&x);
+    /// let mut values = [0; 3];
+    /// 
+    /// Padded::copy_to_slice(padded, &mut values);
+    /// 
+    /// assert_eq!(values, [1, 2, 3]);
+    /// ```
+    pub fn copy_to_slice(slice: &[Self], dst: &mut [T])
+    where
+        T: Copy
+    {
+        assert_eq!(slice.len(), dst.len(), "Source and destination slices must have the same length.");
+        let l = slice.len();
+        let mut i = 0;
+        while i < l
+        {
+            dst[i] = slice[i].value;
+            i += 1;
+        }
+    }
+
+    /// Clones the values in a slice of padded values into a contiguous slice.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the two slices have different lengths.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let padded: [Padded<String, 2>; 2] = ["a".to_string(), "b".to_string()].map(Padded::from);
+    /// let mut values = [String::new(), String::new()];
+    /// 
+    /// Padded::clone_to_slice(&padded, &mut values);
+    /// 
+    /// assert_eq!(values, ["a", "b"]);
+    /// ```
+    pub fn clone_to_slice(slice: &[Self], dst: &mut [T])
+    where
+        T: Clone
+    {
+        assert_eq!(slice.len(), dst.len(), "Source and destination slices must have the same length.");
+        let l = slice.len();
+        let mut i = 0;
+        while i < l
+        {
+            dst[i].clone_from(&slice[i].value);
+            i += 1;
+        }
+    }
+
+    /// Clones the values in a slice of padded values into a new contiguous boxed slice.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let padded: [Padded<i32, 3>; 3] = [1, 2, 3].map(Padded::from);
+    /// 
+    /// let values = Padded::to_boxed_slice(&padded);
+    /// 
+    /// assert_eq!(*values, [1, 2, 3]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_boxed_slice(slice: &[Self]) -> alloc::boxed::Box<[T]>
+    where
+        T: Clone
+    {
+        Self::iter_values(slice)
+            .cloned()
+            .collect()
+    }
 }
 
 impl<T, const WIDTH1: usize, const WIDTH2: usize> Padded<Padded<T, WIDTH1>, WIDTH2>
@@ -122,6 +813,20 @@ where
     [(); WIDTH1 - 1]:,
     [(); WIDTH2 - 1]:
 {
+    /// Merges two levels of padding into one.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let nested: Padded<Padded<i32, 2>, 3> = Padded::new(Padded::new(1));
+    /// let flat: Padded<i32, 6> = nested.flatten();
+    /// 
+    /// assert_eq!(flat, 1);
+    /// ```
     pub const fn flatten(self) -> Padded<T, {WIDTH1*WIDTH2}>
     where
         [(); WIDTH1*WIDTH2 - 1]:
@@ -129,23 +834,137 @@ where
         Padded::new(self.into_inner().into_inner())
     }
 
-    pub const fn flatten_slice<const M: usize>(slice: &[Self]) -> &[Padded<T, {WIDTH1*WIDTH2}>]
+    /// Splits one level of padding into two. This is the inverse of [`flatten`](Padded::flatten).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let flat: Padded<i32, 6> = Padded::new(1);
+    /// let nested = Padded::<Padded<i32, 2>, 3>::unflatten(flat);
+    /// 
+    /// assert_eq!(*nested, 1);
+    /// ```
+    pub const fn unflatten(padded: Padded<T, {WIDTH1*WIDTH2}>) -> Self
     where
         [(); WIDTH1*WIDTH2 - 1]:
     {
+        Padded::new(Padded::new(padded.into_inner()))
+    }
+
+    /// Views a slice of doubly padded values as a slice of singly padded values.
+    /// 
+    /// Both have the same layout, since each level of padding is laid out as an array of `T`.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let nested: [Padded<Padded<i32, 2>, 2>; 3] = [1, 2, 3].map(|x| Padded::new(Padded::new(x)));
+    /// 
+    /// let flat: &[Padded<i32, 4>] = Padded::flatten_slice(&nested);
+    /// 
+    /// assert_eq!(flat, [1, 2, 3]);
+    /// ```
+    pub const fn flatten_slice(slice: &[Self]) -> &[Padded<T, {WIDTH1*WIDTH2}>]
+    where
+        [(); WIDTH1*WIDTH2 - 1]:
+    {
+        assert!(Self::SAME_LAYOUT);
         unsafe {
             core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len())
         }
     }
     
-    pub const fn flatten_mut_slice<const M: usize>(slice: &mut [Self]) -> &mut [Padded<T, {WIDTH1*WIDTH2}>]
+    /// Views a mutable slice of doubly padded values as a mutable slice of singly padded values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let mut nested: [Padded<Padded<i32, 2>, 2>; 3] = [1, 2, 3].map(|x| Padded::new(Padded::new(x)));
+    /// 
+    /// for x in Padded::flatten_mut_slice(&mut nested)
+    /// {
+    ///     **x += 1;
+    /// }
+    /// 
+    /// assert_eq!(nested.map(|x| x.flatten()), [2, 3, 4]);
+    /// ```
+    pub const fn flatten_mut_slice(slice: &mut [Self]) -> &mut [Padded<T, {WIDTH1*WIDTH2}>]
+    where
+        [(); WIDTH1*WIDTH2 - 1]:
+    {
+        assert!(Self::SAME_LAYOUT);
+        unsafe {
+            core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len())
+        }
+    }
+
+    /// Views a slice of singly padded values as a slice of doubly padded values. This is the inverse of [`flatten_slice`](Padded::flatten_slice).
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let flat: [Padded<i32, 6>; 2] = [1, 2].map(Padded::new);
+    /// 
+    /// let nested = Padded::<Padded<i32, 3>, 2>::unflatten_slice(&flat);
+    /// 
+    /// assert_eq!(**nested[1], 2);
+    /// ```
+    pub const fn unflatten_slice(slice: &[Padded<T, {WIDTH1*WIDTH2}>]) -> &[Self]
+    where
+        [(); WIDTH1*WIDTH2 - 1]:
+    {
+        assert!(Self::SAME_LAYOUT);
+        unsafe {
+            core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len())
+        }
+    }
+
+    /// Views a mutable slice of singly padded values as a mutable slice of doubly padded values.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    /// 
+    /// use slice_ops::padded::Padded;
+    /// 
+    /// let mut flat: [Padded<i32, 6>; 2] = [1, 2].map(Padded::new);
+    /// 
+    /// for x in Padded::<Padded<i32, 2>, 3>::unflatten_mut_slice(&mut flat)
+    /// {
+    ///     ***x *= 10;
+    /// }
+    /// 
+    /// assert_eq!(flat, [10, 20]);
+    /// ```
+    pub const fn unflatten_mut_slice(slice: &mut [Padded<T, {WIDTH1*WIDTH2}>]) -> &mut [Self]
     where
         [(); WIDTH1*WIDTH2 - 1]:
     {
+        assert!(Self::SAME_LAYOUT);
         unsafe {
             core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len())
         }
     }
+
+    const SAME_LAYOUT: bool = core::mem::size_of::<Self>() == WIDTH1*WIDTH2*core::mem::size_of::<T>()
+        && core::mem::align_of::<Self>() == core::mem::align_of::<T>();
 }
 
 impl<T, const WIDTH: usize> Borrow<T> for Padded<T, WIDTH>
@@ -185,4 +1004,55 @@ where
     {
         self.borrow_mut()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::Padded;
+
+    #[test]
+    fn ops()
+    {
+        let mut x: Padded<i32, 3> = Padded::from(4);
+
+        assert_eq!(x + 1, 5);
+        assert_eq!(-x, -4);
+        x *= 3;
+        x -= 2;
+        assert_eq!(x, 10);
+
+        let y = x;
+        assert_eq!(x, y);
+        assert_eq!(Padded::<i32, 2>::default(), 0);
+
+        x += y;
+        assert_eq!(x * y - y, 190);
+        assert_eq!(y << y, 10 << 10);
+
+        let padded: [Padded<i32, 2>; 4] = [1, 2, 3, 4].map(Padded::from);
+        let mut values = [0; 4];
+        Padded::copy_to_slice(&padded, &mut values);
+        assert_eq!(values, [1, 2, 3, 4]);
+        assert!(Padded::iter_values(&padded).rev().eq(&[4, 3, 2, 1]));
+
+        let x = [1, 2, 3, 4, 5];
+        let (chunks, rest) = Padded::<_, 2>::as_chunks(&x);
+        assert_eq!(chunks, [1, 3]);
+        assert_eq!(rest, [5]);
+        let (chunks, rest) = Padded::<_, 8>::as_chunks(&x);
+        assert!(chunks.is_empty());
+        assert_eq!(rest, x);
+    }
+
+    #[test]
+    fn flatten()
+    {
+        let mut flat: [Padded<u8, 6>; 4] = [1, 2, 3, 4].map(Padded::new);
+
+        let nested = Padded::<Padded<u8, 2>, 3>::unflatten_mut_slice(&mut flat);
+        nested[2] = Padded::unflatten(Padded::new(30));
+        assert_eq!(Padded::flatten_slice(nested), [1, 2, 30, 4]);
+        assert_eq!(flat, [1, 2, 30, 4]);
+    }
+}
@@ -0,0 +1,99 @@
+//! Implementations of the visiting, searching and reducing operations for containers that are not slices, in terms of their iterators.
+
+pub fn visit<I, F>(iter: I, visitor: F)
+where
+    I: Iterator,
+    F: FnMut(I::Item)
+{
+    iter.for_each(visitor)
+}
+
+pub fn try_visit<I, E, F>(mut iter: I, visitor: F) -> Result<(), E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<(), E>
+{
+    iter.try_for_each(visitor)
+}
+
+pub fn rvisit<I, F>(iter: I, visitor: F)
+where
+    I: DoubleEndedIterator,
+    F: FnMut(I::Item)
+{
+    iter.rev().for_each(visitor)
+}
+
+pub fn try_rvisit<I, E, F>(iter: I, visitor: F) -> Result<(), E>
+where
+    I: DoubleEndedIterator,
+    F: FnMut(I::Item) -> Result<(), E>
+{
+    iter.rev().try_for_each(visitor)
+}
+
+pub fn find_by<I, F>(mut iter: I, f: F) -> Option<usize>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> bool
+{
+    iter.position(f)
+}
+
+/// `len` is the number of items in `iter`, which is taken separately since chained iterators are not [`ExactSizeIterator`]s.
+pub fn rfind_by<I, F>(iter: I, len: usize, mut f: F) -> Option<usize>
+where
+    I: DoubleEndedIterator,
+    F: FnMut(I::Item) -> bool
+{
+    let mut i = len;
+    for x in iter.rev()
+    {
+        i -= 1;
+        if f(x)
+        {
+            return Some(i)
+        }
+    }
+    None
+}
+
+pub fn argreduce<I, F>(iter: I, mut f: F) -> Option<usize>
+where
+    I: Iterator,
+    I::Item: Copy,
+    F: FnMut(I::Item, I::Item) -> bool
+{
+    let mut iter = iter.enumerate();
+    let (mut j, mut best) = iter.next()?;
+    for (i, x) in iter
+    {
+        if f(x, best)
+        {
+            j = i;
+            best = x;
+        }
+    }
+    Some(j)
+}
+
+pub fn argreduce_key<I, K, FR, FB>(iter: I, mut predicate: FR, mut hasher: FB) -> Option<usize>
+where
+    I: Iterator,
+    FR: FnMut(&K, &K) -> bool,
+    FB: FnMut(I::Item) -> K
+{
+    let mut iter = iter.enumerate();
+    let (mut j, first) = iter.next()?;
+    let mut key = hasher(first);
+    for (i, x) in iter
+    {
+        let next_key = hasher(x);
+        if predicate(&next_key, &key)
+        {
+            j = i;
+            key = next_key;
+        }
+    }
+    Some(j)
+}
@@ -1,5 +1,7 @@
 moddef::moddef!(
     flat(pub) mod {
-        boxed for cfg(feature = "alloc")
+        boxed for cfg(feature = "alloc"),
+        iter_ops,
+        sealed
     }
 );
\ No newline at end of file
@@ -0,0 +1,5 @@
+/// Prevents downstream crates from implementing traits whose implementors the crate relies on for soundness.
+pub trait Sealed
+{
+
+}
@@ -0,0 +1,849 @@
+use core::{borrow::{Borrow, BorrowMut}, cmp::Ordering, iter::Chain, marker::PhantomData, ops::{Index, IndexMut}};
+
+use crate::private;
+
+/// A ring buffer view of a borrowed slice.
+///
+/// See [`Ring`] for the available operations.
+pub type RingBuffer<'a, T> = Ring<T, &'a mut [T]>;
+
+/// A ring buffer that owns an array.
+///
+/// See [`Ring`] for the available operations.
+pub type ArrayRingBuffer<T, const N: usize> = Ring<T, [T; N]>;
+
+/// A fixed-capacity double-ended queue stored in a contiguous buffer, with O(1) push and pop at both ends.
+///
+/// Every slot in the buffer always holds a valid value. Slots outside the current contents keep whatever value they last had,
+/// so popping an element only hands out a reference to its slot, and pushing into a full buffer evicts the element at the opposite end.
+///
+/// This makes it suitable as a delay line or history buffer, where [`SliceShift`](crate::ops::SliceShift) would take O(n) per shift.
+///
+/// The contents usually wrap around the end of the buffer, so visits, searches and reductions are provided as inherent methods that walk both halves
+/// of [`as_slices`](Ring::as_slices) in order, and return indices counted from the front. To apply the [`ops`](crate::ops) traits to the contents
+/// instead, call [`make_contiguous`](Ring::make_contiguous) first.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::ring_buffer::RingBuffer;
+///
+/// let mut history = [0; 4];
+/// let mut ring = RingBuffer::new(&mut history);
+///
+/// for x in 1..=6
+/// {
+///     ring.push_back(x);
+/// }
+///
+/// assert_eq!(ring.as_slices(), (&[3, 4][..], &[5, 6][..]));
+/// assert_eq!(ring[0], 3);
+/// assert_eq!(ring.argmax(), Some(3));
+/// ```
+pub struct Ring<T, B>
+where
+    B: BorrowMut<[T]>
+{
+    buffer: B,
+    head: usize,
+    len: usize,
+    marker: PhantomData<T>
+}
+
+impl<T, B> Ring<T, B>
+where
+    B: BorrowMut<[T]>
+{
+    /// Creates a full ring buffer, whose contents are the current contents of `buffer`, from front to back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+    ///
+    /// assert!(ring.is_full());
+    /// assert_eq!(ring.push_back(4), Some(1));
+    /// assert_eq!(ring.make_contiguous(), [2, 3, 4]);
+    /// ```
+    pub fn new(buffer: B) -> Self
+    {
+        let len = Borrow::<[T]>::borrow(&buffer).len();
+        Self {
+            buffer,
+            head: 0,
+            len,
+            marker: PhantomData
+        }
+    }
+
+    /// Creates an empty ring buffer, with the capacity of `buffer`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::RingBuffer;
+    ///
+    /// let mut buffer = [0; 3];
+    /// let mut ring = RingBuffer::empty(&mut buffer);
+    ///
+    /// assert!(ring.is_empty());
+    /// assert_eq!(ring.push_back(1), None);
+    /// assert_eq!(ring.len(), 1);
+    /// ```
+    pub fn empty(buffer: B) -> Self
+    {
+        Self {
+            buffer,
+            head: 0,
+            len: 0,
+            marker: PhantomData
+        }
+    }
+
+    /// Returns the underlying buffer, in its physical order.
+    pub fn into_inner(self) -> B
+    {
+        self.buffer
+    }
+
+    /// Returns the maximum number of elements the ring buffer can hold.
+    pub fn capacity(&self) -> usize
+    {
+        self.slice().len()
+    }
+
+    /// Returns the number of elements in the ring buffer.
+    pub fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    /// Returns `true` if the ring buffer has no elements.
+    pub fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    /// Returns `true` if the ring buffer has as many elements as its capacity.
+    pub fn is_full(&self) -> bool
+    {
+        self.len == self.capacity()
+    }
+
+    /// Removes all elements from the ring buffer. The values in the buffer are left untouched.
+    pub fn clear(&mut self)
+    {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    fn slice(&self) -> &[T]
+    {
+        self.buffer.borrow()
+    }
+
+    fn slice_mut(&mut self) -> &mut [T]
+    {
+        self.buffer.borrow_mut()
+    }
+
+    fn physical(&self, i: usize) -> usize
+    {
+        let cap = self.capacity();
+        let j = self.head + i;
+        if j >= cap
+        {
+            j - cap
+        }
+        else
+        {
+            j
+        }
+    }
+
+    /// Appends an element to the back of the ring buffer.
+    ///
+    /// If the ring buffer is full, the front element is evicted and returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::RingBuffer;
+    ///
+    /// let mut buffer = [0; 2];
+    /// let mut ring = RingBuffer::empty(&mut buffer);
+    ///
+    /// assert_eq!(ring.push_back(1), None);
+    /// assert_eq!(ring.push_back(2), None);
+    /// assert_eq!(ring.push_back(3), Some(1));
+    ///
+    /// assert_eq!(ring.as_slices(), (&[2][..], &[3][..]));
+    /// ```
+    pub fn push_back(&mut self, value: T) -> Option<T>
+    {
+        let cap = self.capacity();
+        if cap == 0
+        {
+            return Some(value)
+        }
+        if self.len == cap
+        {
+            let i = self.head;
+            self.head = self.physical(1);
+            return Some(core::mem::replace(&mut self.slice_mut()[i], value))
+        }
+        let i = self.physical(self.len);
+        self.slice_mut()[i] = value;
+        self.len += 1;
+        None
+    }
+
+    /// Prepends an element to the front of the ring buffer.
+    ///
+    /// If the ring buffer is full, the back element is evicted and returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::RingBuffer;
+    ///
+    /// let mut buffer = [0; 2];
+    /// let mut ring = RingBuffer::empty(&mut buffer);
+    ///
+    /// assert_eq!(ring.push_front(1), None);
+    /// assert_eq!(ring.push_front(2), None);
+    /// assert_eq!(ring.push_front(3), Some(1));
+    ///
+    /// assert!(ring.iter().eq(&[3, 2]));
+    /// ```
+    pub fn push_front(&mut self, value: T) -> Option<T>
+    {
+        let cap = self.capacity();
+        if cap == 0
+        {
+            return Some(value)
+        }
+        self.head = self.physical(cap - 1);
+        let i = self.head;
+        if self.len == cap
+        {
+            return Some(core::mem::replace(&mut self.slice_mut()[i], value))
+        }
+        self.slice_mut()[i] = value;
+        self.len += 1;
+        None
+    }
+
+    /// Removes the front element of the ring buffer, and returns a reference to the slot it was stored in.
+    ///
+    /// The slot remains valid until the ring buffer is modified again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+    ///
+    /// assert_eq!(ring.pop_front().copied(), Some(1));
+    /// assert_eq!(ring.pop_front().copied(), Some(2));
+    /// assert_eq!(ring.len(), 1);
+    /// ```
+    pub fn pop_front(&mut self) -> Option<&mut T>
+    {
+        if self.len == 0
+        {
+            return None
+        }
+        let i = self.head;
+        self.head = self.physical(1);
+        self.len -= 1;
+        Some(&mut self.slice_mut()[i])
+    }
+
+    /// Removes the back element of the ring buffer, and returns a reference to the slot it was stored in.
+    ///
+    /// The slot remains valid until the ring buffer is modified again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+    ///
+    /// assert_eq!(ring.pop_back().copied(), Some(3));
+    /// assert_eq!(ring.pop_back().copied(), Some(2));
+    /// assert_eq!(ring.len(), 1);
+    /// ```
+    pub fn pop_back(&mut self) -> Option<&mut T>
+    {
+        if self.len == 0
+        {
+            return None
+        }
+        self.len -= 1;
+        let i = self.physical(self.len);
+        Some(&mut self.slice_mut()[i])
+    }
+
+    /// Returns a reference to the element at index `i` from the front, or `None` if out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+    /// ring.push_back(4);
+    ///
+    /// assert_eq!(ring.get(0), Some(&2));
+    /// assert_eq!(ring.get(2), Some(&4));
+    /// assert_eq!(ring.get(3), None);
+    /// ```
+    pub fn get(&self, i: usize) -> Option<&T>
+    {
+        if i < self.len
+        {
+            Some(&self.slice()[self.physical(i)])
+        }
+        else
+        {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at index `i` from the front, or `None` if out of bounds.
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T>
+    {
+        if i < self.len
+        {
+            let j = self.physical(i);
+            Some(&mut self.slice_mut()[j])
+        }
+        else
+        {
+            None
+        }
+    }
+
+    /// Returns a reference to the front element, or `None` if empty.
+    pub fn front(&self) -> Option<&T>
+    {
+        self.get(0)
+    }
+
+    /// Returns a mutable reference to the front element, or `None` if empty.
+    pub fn front_mut(&mut self) -> Option<&mut T>
+    {
+        self.get_mut(0)
+    }
+
+    /// Returns a reference to the back element, or `None` if empty.
+    pub fn back(&self) -> Option<&T>
+    {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    /// Returns a mutable reference to the back element, or `None` if empty.
+    pub fn back_mut(&mut self) -> Option<&mut T>
+    {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// Returns the contents of the ring buffer as two slices, which in order make up the elements from front to back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3, 4]);
+    /// ring.pop_back();
+    /// ring.push_front(0);
+    /// ring.push_front(-1);
+    ///
+    /// assert_eq!(ring.as_slices(), (&[-1, 0][..], &[1, 2][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T])
+    {
+        let buffer = self.slice();
+        let end = self.head + self.len;
+        let cap = buffer.len();
+        if end <= cap
+        {
+            (&buffer[self.head..end], &[])
+        }
+        else
+        {
+            let (left, right) = buffer.split_at(self.head);
+            (right, &left[..end - cap])
+        }
+    }
+
+    /// Returns the contents of the ring buffer as two mutable slices, which in order make up the elements from front to back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::{ops::*, ring_buffer::ArrayRingBuffer};
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+    /// ring.push_back(4);
+    ///
+    /// let (a, b) = ring.as_mut_slices();
+    /// a.mul_assign_all(10);
+    /// b.mul_assign_all(10);
+    ///
+    /// assert!(ring.iter().eq(&[20, 30, 40]));
+    /// ```
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T])
+    {
+        let head = self.head;
+        let end = head + self.len;
+        let buffer = self.slice_mut();
+        let cap = buffer.len();
+        if end <= cap
+        {
+            (&mut buffer[head..end], &mut [])
+        }
+        else
+        {
+            let (left, right) = buffer.split_at_mut(head);
+            (right, &mut left[..end - cap])
+        }
+    }
+
+    /// Rotates the underlying buffer so that the contents are stored contiguously from its start, and returns them as a single slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3, 4]);
+    /// ring.push_back(5);
+    /// ring.push_back(6);
+    ///
+    /// assert_eq!(ring.make_contiguous(), [3, 4, 5, 6]);
+    /// assert_eq!(ring.into_inner(), [3, 4, 5, 6]);
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T]
+    {
+        let head = self.head;
+        let len = self.len;
+        self.head = 0;
+        let buffer = self.slice_mut();
+        buffer.rotate_left(head);
+        &mut buffer[..len]
+    }
+
+    /// Returns an iterator over the elements from front to back.
+    pub fn iter(&self) -> Chain<core::slice::Iter<'_, T>, core::slice::Iter<'_, T>>
+    {
+        let (a, b) = self.as_slices();
+        a.iter()
+            .chain(b.iter())
+    }
+
+    /// Returns an iterator over mutable references to the elements from front to back.
+    pub fn iter_mut(&mut self) -> Chain<core::slice::IterMut<'_, T>, core::slice::IterMut<'_, T>>
+    {
+        let (a, b) = self.as_mut_slices();
+        a.iter_mut()
+            .chain(b.iter_mut())
+    }
+
+    /// Visits each element once, from front to back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+    /// ring.push_back(4);
+    ///
+    /// let mut visited = vec![];
+    /// ring.visit(|&x| visited.push(x));
+    ///
+    /// assert_eq!(visited, [2, 3, 4]);
+    /// ```
+    pub fn visit<'a, F>(&'a self, visitor: F)
+    where
+        F: FnMut(&'a T)
+    {
+        private::visit(self.iter(), visitor)
+    }
+    /// Mutably visits each element once, from front to back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+    /// ring.push_back(4);
+    ///
+    /// let mut i = 0;
+    /// ring.visit_mut(|x| {
+    ///     *x = i;
+    ///     i += 1;
+    /// });
+    ///
+    /// assert_eq!(ring.into_inner(), [2, 0, 1]);
+    /// ```
+    pub fn visit_mut<'a, F>(&'a mut self, visitor: F)
+    where
+        F: FnMut(&'a mut T)
+    {
+        private::visit(self.iter_mut(), visitor)
+    }
+    /// Visits each element once, from front to back, or short-circuits if the visitor returns an error.
+    pub fn try_visit<'a, E, F>(&'a self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'a T) -> Result<(), E>
+    {
+        private::try_visit(self.iter(), visitor)
+    }
+    /// Mutably visits each element once, from front to back, or short-circuits if the visitor returns an error.
+    pub fn try_visit_mut<'a, E, F>(&'a mut self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'a mut T) -> Result<(), E>
+    {
+        private::try_visit(self.iter_mut(), visitor)
+    }
+    /// Visits each element once, from back to front.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+    /// ring.push_back(4);
+    ///
+    /// let mut visited = vec![];
+    /// ring.rvisit(|&x| visited.push(x));
+    ///
+    /// assert_eq!(visited, [4, 3, 2]);
+    /// ```
+    pub fn rvisit<'a, F>(&'a self, visitor: F)
+    where
+        F: FnMut(&'a T)
+    {
+        private::rvisit(self.iter(), visitor)
+    }
+    /// Mutably visits each element once, from back to front.
+    pub fn rvisit_mut<'a, F>(&'a mut self, visitor: F)
+    where
+        F: FnMut(&'a mut T)
+    {
+        private::rvisit(self.iter_mut(), visitor)
+    }
+    /// Visits each element once, from back to front, or short-circuits if the visitor returns an error.
+    pub fn try_rvisit<'a, E, F>(&'a self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'a T) -> Result<(), E>
+    {
+        private::try_rvisit(self.iter(), visitor)
+    }
+    /// Mutably visits each element once, from back to front, or short-circuits if the visitor returns an error.
+    pub fn try_rvisit_mut<'a, E, F>(&'a mut self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'a mut T) -> Result<(), E>
+    {
+        private::try_rvisit(self.iter_mut(), visitor)
+    }
+
+    /// Finds the index, from the front, of the first element equal to `x`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+    /// ring.push_back(1);
+    ///
+    /// assert_eq!(ring.find(&1), Some(2));
+    /// assert_eq!(ring.find(&4), None);
+    /// ```
+    pub fn find(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq
+    {
+        self.find_by(|e| e == x)
+    }
+    /// Finds the index, from the front, of the first element satisfying the predicate.
+    pub fn find_by<'a, F>(&'a self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> bool
+    {
+        private::find_by(self.iter(), f)
+    }
+    /// Finds the index, from the front, of the first element whose key equals `b`.
+    pub fn find_by_key<'a, K, F>(&'a self, b: &K, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> K,
+        K: PartialEq
+    {
+        self.find_by(|e| f(e) == *b)
+    }
+    /// Finds the index, from the front, of the last element equal to `x`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([1, 2, 1]);
+    /// ring.push_back(3);
+    ///
+    /// assert_eq!(ring.rfind(&1), Some(1));
+    /// ```
+    pub fn rfind(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq
+    {
+        self.rfind_by(|e| e == x)
+    }
+    /// Finds the index, from the front, of the last element satisfying the predicate.
+    pub fn rfind_by<'a, F>(&'a self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> bool
+    {
+        private::rfind_by(self.iter(), self.len(), f)
+    }
+    /// Finds the index, from the front, of the last element whose key equals `b`.
+    pub fn rfind_by_key<'a, K, F>(&'a self, b: &K, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> K,
+        K: PartialEq
+    {
+        self.rfind_by(|e| f(e) == *b)
+    }
+
+    /// Returns the index, from the front, of the element that is preferred by the reduction.
+    ///
+    /// See [`SliceArgReduce::argreduce`](crate::ops::SliceArgReduce::argreduce).
+    pub fn argreduce<'a, F>(&'a self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T, &'a T) -> bool
+    {
+        private::argreduce(self.iter(), f)
+    }
+    /// Returns the index, from the front, of the element whose key is preferred by the reduction.
+    ///
+    /// See [`SliceArgReduce::argreduce_key`](crate::ops::SliceArgReduce::argreduce_key).
+    pub fn argreduce_key<'a, K, FR, FB>(&'a self, predicate: FR, hasher: FB) -> Option<usize>
+    where
+        FR: FnMut(&K, &K) -> bool,
+        FB: FnMut(&'a T) -> K
+    {
+        private::argreduce_key(self.iter(), predicate, hasher)
+    }
+
+    /// Returns the index, from the front, of the maximum element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([9, 2, 3]);
+    /// ring.push_back(1);
+    ///
+    /// assert_eq!(ring.argmax(), Some(1));
+    /// ```
+    pub fn argmax(&self) -> Option<usize>
+    where
+        T: PartialOrd
+    {
+        self.argreduce(PartialOrd::gt)
+    }
+    /// Returns the index, from the front, of the minimum element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::ring_buffer::ArrayRingBuffer;
+    ///
+    /// let mut ring = ArrayRingBuffer::new([0, 2, 3]);
+    /// ring.push_back(1);
+    ///
+    /// assert_eq!(ring.argmin(), Some(2));
+    /// ```
+    pub fn argmin(&self) -> Option<usize>
+    where
+        T: PartialOrd
+    {
+        self.argreduce(PartialOrd::lt)
+    }
+    /// Returns the index, from the front, of the maximum element with respect to the given comparison function.
+    pub fn argmax_by<'a, F>(&'a self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering
+    {
+        self.argreduce(|a, b| matches!(f(a, b), Ordering::Greater))
+    }
+    /// Returns the index, from the front, of the minimum element with respect to the given comparison function.
+    pub fn argmin_by<'a, F>(&'a self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering
+    {
+        self.argreduce(|a, b| matches!(f(a, b), Ordering::Less))
+    }
+    /// Returns the index, from the front, of the element with the maximum key.
+    pub fn argmax_by_key<'a, K, F>(&'a self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> K,
+        K: PartialOrd
+    {
+        self.argreduce_key(PartialOrd::gt, f)
+    }
+    /// Returns the index, from the front, of the element with the minimum key.
+    pub fn argmin_by_key<'a, K, F>(&'a self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> K,
+        K: PartialOrd
+    {
+        self.argreduce_key(PartialOrd::lt, f)
+    }
+}
+
+impl<T, B> Index<usize> for Ring<T, B>
+where
+    B: BorrowMut<[T]>
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output
+    {
+        match self.get(index)
+        {
+            Some(x) => x,
+            None => panic!("Index {} out of range for ring buffer of length {}.", index, self.len)
+        }
+    }
+}
+impl<T, B> IndexMut<usize> for Ring<T, B>
+where
+    B: BorrowMut<[T]>
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output
+    {
+        let len = self.len;
+        match self.get_mut(index)
+        {
+            Some(x) => x,
+            None => panic!("Index {} out of range for ring buffer of length {}.", index, len)
+        }
+    }
+}
+
+impl<T, B> Clone for Ring<T, B>
+where
+    B: BorrowMut<[T]> + Clone
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            buffer: self.buffer.clone(),
+            head: self.head,
+            len: self.len,
+            marker: PhantomData
+        }
+    }
+}
+
+impl<T, B> core::fmt::Debug for Ring<T, B>
+where
+    B: BorrowMut<[T]>,
+    T: core::fmt::Debug
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.debug_list()
+            .entries(self.iter())
+            .finish()
+    }
+}
+
+impl<'a, T, B> IntoIterator for &'a Ring<T, B>
+where
+    B: BorrowMut<[T]>
+{
+    type Item = &'a T;
+    type IntoIter = Chain<core::slice::Iter<'a, T>, core::slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter()
+    }
+}
+impl<'a, T, B> IntoIterator for &'a mut Ring<T, B>
+where
+    B: BorrowMut<[T]>
+{
+    type Item = &'a mut T;
+    type IntoIter = Chain<core::slice::IterMut<'a, T>, core::slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use std::collections::VecDeque;
+
+    use super::{ArrayRingBuffer, RingBuffer};
+
+    #[test]
+    fn it_works()
+    {
+        let mut buffer = [0; 5];
+        let mut ring = RingBuffer::empty(&mut buffer);
+        let mut reference = VecDeque::new();
+
+        for k in 0..200
+        {
+            match k % 7
+            {
+                0 | 3 => {
+                    let evicted = ring.push_back(k);
+                    reference.push_back(k);
+                    if reference.len() > 5
+                    {
+                        assert_eq!(evicted, reference.pop_front());
+                    }
+                },
+                1 | 5 => {
+                    let evicted = ring.push_front(k);
+                    reference.push_front(k);
+                    if reference.len() > 5
+                    {
+                        assert_eq!(evicted, reference.pop_back());
+                    }
+                },
+                2 => assert_eq!(ring.pop_front().copied(), reference.pop_front()),
+                _ => assert_eq!(ring.pop_back().copied(), reference.pop_back())
+            }
+            assert!(ring.iter().eq(reference.iter()));
+            assert_eq!(ring.argmax(), reference.iter().enumerate().max_by_key(|&(_, x)| x).map(|(i, _)| i));
+        }
+
+        let contents: Vec<_> = ring.iter().copied().collect();
+        assert_eq!(ring.make_contiguous(), contents);
+
+        let mut ring = ArrayRingBuffer::new([1, 2, 3]);
+        ring.push_back(4);
+        assert_eq!(ring[0], 2);
+        assert_eq!(ring.rfind(&4), Some(2));
+    }
+}
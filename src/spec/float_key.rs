@@ -0,0 +1,55 @@
+pub trait FloatKey
+{
+    type Key: PartialOrd;
+
+    /// Returns a key that can be ordered, or [`None`] if the value is NaN.
+    fn float_key(&self) -> Option<Self::Key>;
+}
+
+impl FloatKey for f32
+{
+    type Key = f32;
+
+    fn float_key(&self) -> Option<Self::Key>
+    {
+        if self.is_nan()
+        {
+            return None
+        }
+        Some(*self)
+    }
+}
+
+impl FloatKey for f64
+{
+    type Key = f64;
+
+    fn float_key(&self) -> Option<Self::Key>
+    {
+        if self.is_nan()
+        {
+            return None
+        }
+        Some(*self)
+    }
+}
+
+/// Complex values are ordered by their magnitude.
+#[cfg(feature = "num")]
+impl<F> FloatKey for num_complex::Complex<F>
+where
+    num_complex::Complex<F>: num_complex::ComplexFloat
+{
+    type Key = <Self as num_complex::ComplexFloat>::Real;
+
+    fn float_key(&self) -> Option<Self::Key>
+    {
+        use num_complex::ComplexFloat;
+
+        if ComplexFloat::is_nan(*self)
+        {
+            return None
+        }
+        Some(ComplexFloat::abs(*self))
+    }
+}
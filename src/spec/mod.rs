@@ -1,6 +1,7 @@
 moddef::moddef!(
     flat(pub) mod {
         conj,
+        float_key,
         square
     }
 );
\ No newline at end of file
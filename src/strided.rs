@@ -0,0 +1,1921 @@
+use core::{cmp::Ordering, iter::FusedIterator, marker::PhantomData, ops::{AddAssign, BitAndAssign, BitOrAssign, BitXorAssign, DivAssign, Index, IndexMut, MulAssign, Neg, Not, RemAssign, ShlAssign, ShrAssign, SubAssign}};
+
+use crate::private;
+
+/// A view of every `stride`'th element of a slice, starting at some offset.
+///
+/// This is the runtime counterpart to the lanes returned by [`SliceSpread::spread`](crate::ops::SliceSpread::spread),
+/// for when the number of lanes is not known at compile time.
+///
+/// Searches and reductions such as [`find`](Strided::find) and [`argmax`](Strided::argmax) are available as inherent methods.
+/// The indices they return count elements of the view, so index `i` refers to element `offset + i*stride` of the underlying slice.
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::strided::Strided;
+///
+/// let arr = [1, 2, 3, 4, 5, 6, 7];
+///
+/// let view = Strided::new(&arr, 1, 3);
+///
+/// assert_eq!(view.len(), 2);
+/// assert_eq!(view, [2, 5]);
+/// assert_eq!(view[1], 5);
+/// ```
+pub struct Strided<'a, T>
+{
+    ptr: *const T,
+    stride: usize,
+    len: usize,
+    marker: PhantomData<&'a [T]>
+}
+
+/// A mutable view of every `stride`'th element of a slice, starting at some offset.
+///
+/// Read-only operations such as [`find`](Strided::find) and [`argmax`](Strided::argmax) are available through [`as_strided`](StridedMut::as_strided).
+///
+/// # Example
+///
+/// ```rust
+/// use slice_ops::strided::StridedMut;
+///
+/// let mut arr = [1, 2, 3, 4, 5, 6, 7];
+///
+/// let mut view = StridedMut::new(&mut arr, 0, 3);
+///
+/// view.add_assign_all(10);
+/// view[1] = 0;
+///
+/// assert_eq!(arr, [11, 2, 3, 0, 5, 6, 17]);
+/// ```
+pub struct StridedMut<'a, T>
+{
+    ptr: *mut T,
+    stride: usize,
+    len: usize,
+    marker: PhantomData<&'a mut [T]>
+}
+
+unsafe impl<'a, T> Send for Strided<'a, T>
+where
+    T: Sync
+{
+
+}
+unsafe impl<'a, T> Sync for Strided<'a, T>
+where
+    T: Sync
+{
+
+}
+unsafe impl<'a, T> Send for StridedMut<'a, T>
+where
+    T: Send
+{
+
+}
+unsafe impl<'a, T> Sync for StridedMut<'a, T>
+where
+    T: Sync
+{
+
+}
+
+const fn strided_len(len: usize, offset: usize, stride: usize) -> usize
+{
+    assert!(stride != 0, "Stride must be nonzero.");
+    if offset < len
+    {
+        (len - offset - 1)/stride + 1
+    }
+    else
+    {
+        0
+    }
+}
+
+impl<'a, T> Clone for Strided<'a, T>
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+impl<'a, T> Copy for Strided<'a, T>
+{
+
+}
+
+impl<'a, T> Strided<'a, T>
+{
+    /// Creates a view of every `stride`'th element of `slice`, starting at `offset`.
+    ///
+    /// If `offset` is past the end of the slice, the view is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 4, 5, 6];
+    ///
+    /// assert_eq!(Strided::new(&arr, 0, 2), [1, 3, 5]);
+    /// assert_eq!(Strided::new(&arr, 3, 1), [4, 5, 6]);
+    /// assert!(Strided::new(&arr, 6, 1).is_empty());
+    /// ```
+    pub const fn new(slice: &'a [T], offset: usize, stride: usize) -> Self
+    {
+        let len = strided_len(slice.len(), offset, stride);
+        let ptr = if len > 0
+        {
+            unsafe {
+                slice.as_ptr().add(offset)
+            }
+        }
+        else
+        {
+            slice.as_ptr()
+        };
+        unsafe {
+            Self::from_raw_parts(ptr, stride, len)
+        }
+    }
+
+    /// Creates a strided view from a pointer to its first element, a stride and a length.
+    ///
+    /// # Safety
+    ///
+    /// Every element `ptr.add(i*stride)` for `i < len` must be valid for reads and initialized for the lifetime `'a`, and must not be mutated during it.
+    /// `ptr` must be non-null and aligned, even if `len` is zero.
+    pub const unsafe fn from_raw_parts(ptr: *const T, stride: usize, len: usize) -> Self
+    {
+        Self {
+            ptr,
+            stride,
+            len,
+            marker: PhantomData
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    pub const fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    /// Returns `true` if the view has no elements.
+    pub const fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    /// Returns the distance, in elements of the underlying slice, between two consecutive elements of the view.
+    pub const fn stride(&self) -> usize
+    {
+        self.stride
+    }
+
+    /// Returns a reference to the element at index `i` of the view, or `None` if out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.get(2), Some(&5));
+    /// assert_eq!(view.get(3), None);
+    /// ```
+    pub const fn get(&self, i: usize) -> Option<&'a T>
+    {
+        if i < self.len
+        {
+            Some(unsafe {
+                self.get_unchecked(i)
+            })
+        }
+        else
+        {
+            None
+        }
+    }
+
+    /// Returns a reference to the element at index `i` of the view, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than the length of the view.
+    pub const unsafe fn get_unchecked(&self, i: usize) -> &'a T
+    {
+        unsafe {
+            &*self.ptr.add(i*self.stride)
+        }
+    }
+
+    /// Returns an iterator over the elements of the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let view = Strided::new(&arr, 1, 2);
+    ///
+    /// assert_eq!(view.iter().copied().sum::<i32>(), 6);
+    /// ```
+    pub const fn iter(&self) -> Iter<'a, T>
+    {
+        Iter {
+            ptr: self.ptr,
+            stride: self.stride,
+            front: 0,
+            back: self.len,
+            marker: PhantomData
+        }
+    }
+
+    /// Visits each element in the view once, from left to right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// let mut visited = vec![];
+    /// view.visit(|&x| visited.push(x));
+    ///
+    /// assert_eq!(visited, [1, 3, 5]);
+    /// ```
+    pub fn visit<F>(&self, visitor: F)
+    where
+        F: FnMut(&'a T)
+    {
+        private::visit(self.iter(), visitor)
+    }
+    /// Visits each element in the view once, from left to right, or short-circuits if the visitor returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// let result = view.try_visit(|&x| if x < 3 {Ok(())} else {Err(x)});
+    ///
+    /// assert_eq!(result, Err(3));
+    /// ```
+    pub fn try_visit<E, F>(&self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'a T) -> Result<(), E>
+    {
+        private::try_visit(self.iter(), visitor)
+    }
+    /// Visits each element in the view once, from right to left.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// let mut visited = vec![];
+    /// view.rvisit(|&x| visited.push(x));
+    ///
+    /// assert_eq!(visited, [5, 3, 1]);
+    /// ```
+    pub fn rvisit<F>(&self, visitor: F)
+    where
+        F: FnMut(&'a T)
+    {
+        private::rvisit(self.iter(), visitor)
+    }
+    /// Visits each element in the view once, from right to left, or short-circuits if the visitor returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 4, 5];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// let result = view.try_rvisit(|&x| if x > 3 {Ok(())} else {Err(x)});
+    ///
+    /// assert_eq!(result, Err(3));
+    /// ```
+    pub fn try_rvisit<E, F>(&self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'a T) -> Result<(), E>
+    {
+        private::try_rvisit(self.iter(), visitor)
+    }
+
+    /// Finds the index of the first element in the view equal to `x`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 2, 5, 2];
+    /// let view = Strided::new(&arr, 1, 2);
+    ///
+    /// assert_eq!(view.find(&2), Some(0));
+    /// assert_eq!(view.find(&3), None);
+    /// ```
+    pub fn find(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq
+    {
+        self.find_by(|e| e == x)
+    }
+    /// Finds the index of the first element in the view satisfying the predicate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 4, 5, 6];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.find_by(|&x| x > 2), Some(1));
+    /// ```
+    pub fn find_by<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> bool
+    {
+        private::find_by(self.iter(), f)
+    }
+    /// Finds the index of the first element in the view whose key equals `b`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [-1, 2, -3, 4, 5, 6];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.find_by_key(&3, |x: &i32| x.abs()), Some(1));
+    /// ```
+    pub fn find_by_key<B, F>(&self, b: &B, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: PartialEq
+    {
+        self.find_by(|e| f(e) == *b)
+    }
+    /// Finds the index of the last element in the view equal to `x`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 2, 5, 2];
+    /// let view = Strided::new(&arr, 1, 2);
+    ///
+    /// assert_eq!(view.rfind(&2), Some(2));
+    /// ```
+    pub fn rfind(&self, x: &T) -> Option<usize>
+    where
+        T: PartialEq
+    {
+        self.rfind_by(|e| e == x)
+    }
+    /// Finds the index of the last element in the view satisfying the predicate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 2, 3, 4, 5, 6];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.rfind_by(|&x| x < 4), Some(1));
+    /// ```
+    pub fn rfind_by<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> bool
+    {
+        private::rfind_by(self.iter(), self.len, f)
+    }
+    /// Finds the index of the last element in the view whose key equals `b`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [-1, 2, 1, 4, 5, 6];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.rfind_by_key(&1, |x: &i32| x.abs()), Some(1));
+    /// ```
+    pub fn rfind_by_key<B, F>(&self, b: &B, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: PartialEq
+    {
+        self.rfind_by(|e| f(e) == *b)
+    }
+
+    /// Returns the index of the element in the view that is preferred by the reduction.
+    ///
+    /// See [`SliceArgReduce::argreduce`](crate::ops::SliceArgReduce::argreduce).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 9, 3, 8, 2, 7];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.argreduce(|a, b| a > b), Some(1));
+    /// ```
+    pub fn argreduce<F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T, &'a T) -> bool
+    {
+        private::argreduce(self.iter(), f)
+    }
+    /// Returns the index of the element in the view whose key is preferred by the reduction.
+    ///
+    /// See [`SliceArgReduce::argreduce_key`](crate::ops::SliceArgReduce::argreduce_key).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 9, -3, 8, 2, 7];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.argreduce_key(|a, b| a > b, |x: &i32| x.abs()), Some(1));
+    /// ```
+    pub fn argreduce_key<B, FR, FB>(&self, predicate: FR, hasher: FB) -> Option<usize>
+    where
+        FR: FnMut(&B, &B) -> bool,
+        FB: FnMut(&'a T) -> B
+    {
+        private::argreduce_key(self.iter(), predicate, hasher)
+    }
+
+    /// Returns the index of the maximum element in the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 9, 3, 8, 2, 7];
+    /// let view = Strided::new(&arr, 1, 2);
+    ///
+    /// assert_eq!(view.argmax(), Some(0));
+    /// ```
+    pub fn argmax(&self) -> Option<usize>
+    where
+        T: PartialOrd
+    {
+        self.argreduce(PartialOrd::gt)
+    }
+    /// Returns the index of the minimum element in the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 9, 3, 8, 2, 7];
+    /// let view = Strided::new(&arr, 1, 2);
+    ///
+    /// assert_eq!(view.argmin(), Some(2));
+    /// ```
+    pub fn argmin(&self) -> Option<usize>
+    where
+        T: PartialOrd
+    {
+        self.argreduce(PartialOrd::lt)
+    }
+    /// Returns the index of the maximum element in the view, with respect to the given comparison function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1.0, 9.0, 3.0, 8.0, 2.0, 7.0];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.argmax_by(f64::total_cmp), Some(1));
+    /// ```
+    pub fn argmax_by<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering
+    {
+        self.argreduce(|a, b| matches!(f(a, b), Ordering::Greater))
+    }
+    /// Returns the index of the minimum element in the view, with respect to the given comparison function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1.0, 9.0, 3.0, 8.0, 2.0, 7.0];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.argmin_by(f64::total_cmp), Some(0));
+    /// ```
+    pub fn argmin_by<F>(&self, mut f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T, &'a T) -> Ordering
+    {
+        self.argreduce(|a, b| matches!(f(a, b), Ordering::Less))
+    }
+    /// Returns the index of the element in the view with the maximum key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [1, 9, -3, 8, 2, 7];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.argmax_by_key(|x: &i32| x.abs()), Some(1));
+    /// ```
+    pub fn argmax_by_key<B, F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: PartialOrd
+    {
+        self.argreduce_key(PartialOrd::gt, f)
+    }
+    /// Returns the index of the element in the view with the minimum key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [-4, 9, -3, 8, 2, 7];
+    /// let view = Strided::new(&arr, 0, 2);
+    ///
+    /// assert_eq!(view.argmin_by_key(|x: &i32| x.abs()), Some(2));
+    /// ```
+    pub fn argmin_by_key<B, F>(&self, f: F) -> Option<usize>
+    where
+        F: FnMut(&'a T) -> B,
+        B: PartialOrd
+    {
+        self.argreduce_key(PartialOrd::lt, f)
+    }
+
+    /// Spreads the elements of the view equally across `M` strided views, like [`SliceSpread::spread`](crate::ops::SliceSpread::spread).
+    /// 
+    /// Spreading a lane again gives multi-level lanes, which is useful for decimating in several steps.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use slice_ops::ops::*;
+    ///
+    /// let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    ///
+    /// let [even, _] = arr.spread();
+    /// let [a, b, c] = even.spread();
+    ///
+    /// assert_eq!(a, [0, 6]);
+    /// assert_eq!(b, [2, 8]);
+    /// assert_eq!(c, [4, 10]);
+    /// ```
+    pub fn spread<const M: usize>(&self) -> [Strided<'a, T>; M]
+    where
+        [(); M - 1]:
+    {
+        let lanes = SpreadDyn::from_strided(*self, M);
+        core::array::from_fn(|i| lanes.lane(i))
+    }
+
+    /// Spreads the elements of the view equally across `m` strided views, where `m` may be chosen at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::Strided;
+    ///
+    /// let arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    /// let view = Strided::new(&arr, 1, 3);
+    ///
+    /// let lanes: Vec<_> = view.spread_dyn(2).collect();
+    ///
+    /// assert_eq!(lanes[0], [1, 7]);
+    /// assert_eq!(lanes[1], [4]);
+    /// ```
+    pub const fn spread_dyn(&self, m: usize) -> SpreadDyn<'a, T>
+    {
+        SpreadDyn::from_strided(*self, m)
+    }
+}
+
+impl<'a, T> StridedMut<'a, T>
+{
+    /// Creates a mutable view of every `stride`'th element of `slice`, starting at `offset`.
+    ///
+    /// If `offset` is past the end of the slice, the view is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5, 6];
+    ///
+    /// let mut view = StridedMut::new(&mut arr, 1, 2);
+    /// view[2] = 0;
+    ///
+    /// assert_eq!(view, [2, 4, 0]);
+    /// ```
+    pub const fn new(slice: &'a mut [T], offset: usize, stride: usize) -> Self
+    {
+        let len = strided_len(slice.len(), offset, stride);
+        let ptr = if len > 0
+        {
+            unsafe {
+                slice.as_mut_ptr().add(offset)
+            }
+        }
+        else
+        {
+            slice.as_mut_ptr()
+        };
+        unsafe {
+            Self::from_raw_parts(ptr, stride, len)
+        }
+    }
+
+    /// Creates a mutable strided view from a pointer to its first element, a stride and a length.
+    ///
+    /// # Safety
+    ///
+    /// Every element `ptr.add(i*stride)` for `i < len` must be valid for reads and writes and initialized for the lifetime `'a`, and must not be accessed through any other pointer during it.
+    /// `ptr` must be non-null and aligned, even if `len` is zero.
+    pub const unsafe fn from_raw_parts(ptr: *mut T, stride: usize, len: usize) -> Self
+    {
+        Self {
+            ptr,
+            stride,
+            len,
+            marker: PhantomData
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    pub const fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    /// Returns `true` if the view has no elements.
+    pub const fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    /// Returns the distance, in elements of the underlying slice, between two consecutive elements of the view.
+    pub const fn stride(&self) -> usize
+    {
+        self.stride
+    }
+
+    /// Reborrows the view as a read-only view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut arr = [1, 9, 3, 8, 2, 7];
+    /// let view = StridedMut::new(&mut arr, 1, 2);
+    ///
+    /// assert_eq!(view.as_strided().argmin(), Some(2));
+    /// ```
+    pub const fn as_strided(&self) -> Strided<'_, T>
+    {
+        unsafe {
+            Strided::from_raw_parts(self.ptr, self.stride, self.len)
+        }
+    }
+
+    /// Reborrows the view mutably, for a shorter lifetime.
+    pub const fn as_strided_mut(&mut self) -> StridedMut<'_, T>
+    {
+        unsafe {
+            StridedMut::from_raw_parts(self.ptr, self.stride, self.len)
+        }
+    }
+
+    /// Converts the view into a read-only view of the same lifetime.
+    pub const fn into_strided(self) -> Strided<'a, T>
+    {
+        unsafe {
+            Strided::from_raw_parts(self.ptr, self.stride, self.len)
+        }
+    }
+
+    /// Spreads the elements of the view equally across `M` disjoint mutable strided views, like [`SliceSpread::spread_mut`](crate::ops::SliceSpread::spread_mut).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use slice_ops::ops::*;
+    ///
+    /// let mut arr = [0; 8];
+    ///
+    /// let [mut even, _] = arr.spread_mut();
+    /// let [mut a, mut b] = even.spread_mut();
+    /// a.add_assign_all(1);
+    /// b.add_assign_all(2);
+    ///
+    /// assert_eq!(arr, [1, 0, 2, 0, 1, 0, 2, 0]);
+    /// ```
+    pub fn spread_mut<const M: usize>(&mut self) -> [StridedMut<'_, T>; M]
+    where
+        [(); M - 1]:
+    {
+        let lanes = SpreadDynMut::from_strided_mut(self.as_strided_mut(), M);
+        // Each lane is only taken once, so the views are disjoint.
+        core::array::from_fn(|i| unsafe {
+            lanes.lane(i)
+        })
+    }
+
+    /// Spreads the elements of the view equally across `m` disjoint mutable strided views, where `m` may be chosen at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut arr = [0; 9];
+    /// let mut view = StridedMut::new(&mut arr, 0, 2);
+    ///
+    /// for (i, mut lane) in view.spread_dyn_mut(2).enumerate()
+    /// {
+    ///     lane.add_assign_all(i + 1);
+    /// }
+    ///
+    /// assert_eq!(arr, [1, 0, 2, 0, 1, 0, 2, 0, 1]);
+    /// ```
+    pub const fn spread_dyn_mut(&mut self, m: usize) -> SpreadDynMut<'_, T>
+    {
+        SpreadDynMut::from_strided_mut(self.as_strided_mut(), m)
+    }
+
+    /// Returns a reference to the element at index `i` of the view, or `None` if out of bounds.
+    pub const fn get(&self, i: usize) -> Option<&T>
+    {
+        self.as_strided().get(i)
+    }
+
+    /// Returns a mutable reference to the element at index `i` of the view, or `None` if out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// let mut view = StridedMut::new(&mut arr, 0, 2);
+    ///
+    /// *view.get_mut(2).unwrap() = 0;
+    /// assert_eq!(view.get_mut(3), None);
+    ///
+    /// assert_eq!(arr, [1, 2, 3, 4, 0]);
+    /// ```
+    pub const fn get_mut(&mut self, i: usize) -> Option<&mut T>
+    {
+        if i < self.len
+        {
+            Some(unsafe {
+                self.get_unchecked_mut(i)
+            })
+        }
+        else
+        {
+            None
+        }
+    }
+
+    /// Returns a reference to the element at index `i` of the view, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than the length of the view.
+    pub const unsafe fn get_unchecked(&self, i: usize) -> &T
+    {
+        unsafe {
+            &*self.ptr.add(i*self.stride)
+        }
+    }
+
+    /// Returns a mutable reference to the element at index `i` of the view, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than the length of the view.
+    pub const unsafe fn get_unchecked_mut(&mut self, i: usize) -> &mut T
+    {
+        unsafe {
+            &mut *self.ptr.add(i*self.stride)
+        }
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub const fn iter(&self) -> Iter<'_, T>
+    {
+        self.as_strided().iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements of the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// let mut view = StridedMut::new(&mut arr, 0, 2);
+    ///
+    /// for x in view.iter_mut()
+    /// {
+    ///     *x = -*x;
+    /// }
+    ///
+    /// assert_eq!(arr, [-1, 2, -3, 4, -5]);
+    /// ```
+    pub const fn iter_mut(&mut self) -> IterMut<'_, T>
+    {
+        IterMut {
+            ptr: self.ptr,
+            stride: self.stride,
+            front: 0,
+            back: self.len,
+            marker: PhantomData
+        }
+    }
+
+    /// Visits each element in the view once, from left to right.
+    pub fn visit<'b, F>(&'b self, visitor: F)
+    where
+        F: FnMut(&'b T)
+    {
+        self.as_strided().visit(visitor)
+    }
+    /// Mutably visits each element in the view once, from left to right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// let mut view = StridedMut::new(&mut arr, 1, 2);
+    ///
+    /// let mut i = 0;
+    /// view.visit_mut(|x| {
+    ///     *x = i;
+    ///     i += 1;
+    /// });
+    ///
+    /// assert_eq!(arr, [1, 0, 3, 1, 5]);
+    /// ```
+    pub fn visit_mut<'b, F>(&'b mut self, visitor: F)
+    where
+        F: FnMut(&'b mut T)
+    {
+        private::visit(self.iter_mut(), visitor)
+    }
+    /// Visits each element in the view once, from left to right, or short-circuits if the visitor returns an error.
+    pub fn try_visit<'b, E, F>(&'b self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'b T) -> Result<(), E>
+    {
+        self.as_strided().try_visit(visitor)
+    }
+    /// Mutably visits each element in the view once, from left to right, or short-circuits if the visitor returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// let mut view = StridedMut::new(&mut arr, 0, 2);
+    ///
+    /// let result = view.try_visit_mut(|x| {
+    ///     if *x > 3
+    ///     {
+    ///         return Err(*x)
+    ///     }
+    ///     *x = 0;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(result, Err(5));
+    /// assert_eq!(arr, [0, 2, 0, 4, 5]);
+    /// ```
+    pub fn try_visit_mut<'b, E, F>(&'b mut self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'b mut T) -> Result<(), E>
+    {
+        private::try_visit(self.iter_mut(), visitor)
+    }
+    /// Visits each element in the view once, from right to left.
+    pub fn rvisit<'b, F>(&'b self, visitor: F)
+    where
+        F: FnMut(&'b T)
+    {
+        self.as_strided().rvisit(visitor)
+    }
+    /// Mutably visits each element in the view once, from right to left.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// let mut view = StridedMut::new(&mut arr, 1, 2);
+    ///
+    /// let mut i = 0;
+    /// view.rvisit_mut(|x| {
+    ///     *x = i;
+    ///     i += 1;
+    /// });
+    ///
+    /// assert_eq!(arr, [1, 1, 3, 0, 5]);
+    /// ```
+    pub fn rvisit_mut<'b, F>(&'b mut self, visitor: F)
+    where
+        F: FnMut(&'b mut T)
+    {
+        private::rvisit(self.iter_mut(), visitor)
+    }
+    /// Visits each element in the view once, from right to left, or short-circuits if the visitor returns an error.
+    pub fn try_rvisit<'b, E, F>(&'b self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'b T) -> Result<(), E>
+    {
+        self.as_strided().try_rvisit(visitor)
+    }
+    /// Mutably visits each element in the view once, from right to left, or short-circuits if the visitor returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut arr = [1, 2, 3, 4, 5];
+    /// let mut view = StridedMut::new(&mut arr, 0, 2);
+    ///
+    /// let result = view.try_rvisit_mut(|x| {
+    ///     if *x < 3
+    ///     {
+    ///         return Err(*x)
+    ///     }
+    ///     *x = 0;
+    ///     Ok(())
+    /// });
+    ///
+    /// assert_eq!(result, Err(1));
+    /// assert_eq!(arr, [1, 2, 0, 4, 0]);
+    /// ```
+    pub fn try_rvisit_mut<'b, E, F>(&'b mut self, visitor: F) -> Result<(), E>
+    where
+        F: FnMut(&'b mut T) -> Result<(), E>
+    {
+        private::try_rvisit(self.iter_mut(), visitor)
+    }
+
+    /// Adds `rhs` to each element in the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// StridedMut::new(&mut x, 0, 2).add_assign_all(2);
+    ///
+    /// assert_eq!(x, [3, 2, 5, 4, 7, 6, 9, 8]);
+    /// ```
+    pub fn add_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: AddAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x += rhs)
+    }
+    /// Subtracts `rhs` from each element in the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// StridedMut::new(&mut x, 0, 2).sub_assign_all(2);
+    ///
+    /// assert_eq!(x, [-1, 2, 1, 4, 3, 6, 5, 8]);
+    /// ```
+    pub fn sub_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: SubAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x -= rhs)
+    }
+    /// Multiplies each element in the view with `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// StridedMut::new(&mut x, 0, 2).mul_assign_all(2);
+    ///
+    /// assert_eq!(x, [2, 2, 6, 4, 10, 6, 14, 8]);
+    /// ```
+    pub fn mul_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: MulAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x *= rhs)
+    }
+    /// Divides each element in the view by `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// StridedMut::new(&mut x, 1, 2).div_assign_all(2);
+    ///
+    /// assert_eq!(x, [1, 1, 3, 2, 5, 3, 7, 4]);
+    /// ```
+    pub fn div_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: DivAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x /= rhs)
+    }
+    /// Replaces each element in the view with the remainder of it divided by `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// StridedMut::new(&mut x, 0, 2).rem_assign_all(2);
+    ///
+    /// assert_eq!(x, [1, 2, 1, 4, 1, 6, 1, 8]);
+    /// ```
+    pub fn rem_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: RemAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x %= rhs)
+    }
+    /// Shifts each element in the view to the left by `rhs` bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [1, 2, 3, 4];
+    ///
+    /// StridedMut::new(&mut x, 0, 2).shl_assign_all(1);
+    ///
+    /// assert_eq!(x, [2, 2, 6, 4]);
+    /// ```
+    pub fn shl_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: ShlAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x <<= rhs)
+    }
+    /// Shifts each element in the view to the right by `rhs` bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [1, 2, 3, 4];
+    ///
+    /// StridedMut::new(&mut x, 1, 2).shr_assign_all(1);
+    ///
+    /// assert_eq!(x, [1, 1, 3, 2]);
+    /// ```
+    pub fn shr_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: ShrAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x >>= rhs)
+    }
+    /// Performs a bitwise AND of each element in the view with `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [true, true, false, true];
+    ///
+    /// StridedMut::new(&mut x, 1, 2).bitand_assign_all(false);
+    ///
+    /// assert_eq!(x, [true, false, false, false]);
+    /// ```
+    pub fn bitand_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: BitAndAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x &= rhs)
+    }
+    /// Performs a bitwise OR of each element in the view with `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [false, false, true, false];
+    ///
+    /// StridedMut::new(&mut x, 1, 2).bitor_assign_all(true);
+    ///
+    /// assert_eq!(x, [false, true, true, true]);
+    /// ```
+    pub fn bitor_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: BitOrAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x |= rhs)
+    }
+    /// Performs a bitwise XOR of each element in the view with `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [false, false, true, true];
+    ///
+    /// StridedMut::new(&mut x, 0, 2).bitxor_assign_all(true);
+    ///
+    /// assert_eq!(x, [true, false, false, true]);
+    /// ```
+    pub fn bitxor_assign_all<Rhs>(&mut self, rhs: Rhs)
+    where
+        T: BitXorAssign<Rhs>,
+        Rhs: Copy
+    {
+        self.visit_mut(|x| *x ^= rhs)
+    }
+    /// Negates each element in the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [1, 2, 3, 4];
+    ///
+    /// StridedMut::new(&mut x, 0, 2).neg_assign_all();
+    ///
+    /// assert_eq!(x, [-1, 2, -3, 4]);
+    /// ```
+    pub fn neg_assign_all(&mut self)
+    where
+        T: Neg<Output = T>
+    {
+        self.visit_mut(|x| unsafe {
+            core::ptr::write(x, -core::ptr::read(x))
+        })
+    }
+    /// Performs a logical NOT or bitwise NOT on each element in the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slice_ops::strided::StridedMut;
+    ///
+    /// let mut x = [true, true, false, false];
+    ///
+    /// StridedMut::new(&mut x, 1, 2).not_assign_all();
+    ///
+    /// assert_eq!(x, [true, false, false, true]);
+    /// ```
+    pub fn not_assign_all(&mut self)
+    where
+        T: Not<Output = T>
+    {
+        self.visit_mut(|x| unsafe {
+            core::ptr::write(x, !core::ptr::read(x))
+        })
+    }
+}
+
+impl<'a, T> Index<usize> for Strided<'a, T>
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output
+    {
+        match self.get(index)
+        {
+            Some(x) => x,
+            None => panic!("Index {} out of range for strided view of length {}.", index, self.len)
+        }
+    }
+}
+impl<'a, T> Index<usize> for StridedMut<'a, T>
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output
+    {
+        match self.get(index)
+        {
+            Some(x) => x,
+            None => panic!("Index {} out of range for strided view of length {}.", index, self.len)
+        }
+    }
+}
+impl<'a, T> IndexMut<usize> for StridedMut<'a, T>
+{
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output
+    {
+        let len = self.len;
+        match self.get_mut(index)
+        {
+            Some(x) => x,
+            None => panic!("Index {} out of range for strided view of length {}.", index, len)
+        }
+    }
+}
+
+impl<'a, T> core::fmt::Debug for Strided<'a, T>
+where
+    T: core::fmt::Debug
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.debug_list()
+            .entries(self.iter())
+            .finish()
+    }
+}
+impl<'a, T> core::fmt::Debug for StridedMut<'a, T>
+where
+    T: core::fmt::Debug
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        self.as_strided().fmt(f)
+    }
+}
+
+impl<'a, 'b, T, U> PartialEq<Strided<'b, U>> for Strided<'a, T>
+where
+    T: PartialEq<U>
+{
+    fn eq(&self, other: &Strided<'b, U>) -> bool
+    {
+        self.len == other.len && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+impl<'a, T, U> PartialEq<[U]> for Strided<'a, T>
+where
+    T: PartialEq<U>
+{
+    fn eq(&self, other: &[U]) -> bool
+    {
+        self.len == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+impl<'a, T, U, const N: usize> PartialEq<[U; N]> for Strided<'a, T>
+where
+    T: PartialEq<U>
+{
+    fn eq(&self, other: &[U; N]) -> bool
+    {
+        self.eq(other.as_slice())
+    }
+}
+impl<'a, T> Eq for Strided<'a, T>
+where
+    T: Eq
+{
+
+}
+impl<'a, 'b, T, U> PartialEq<StridedMut<'b, U>> for StridedMut<'a, T>
+where
+    T: PartialEq<U>
+{
+    fn eq(&self, other: &StridedMut<'b, U>) -> bool
+    {
+        self.as_strided().eq(&other.as_strided())
+    }
+}
+impl<'a, T, U> PartialEq<[U]> for StridedMut<'a, T>
+where
+    T: PartialEq<U>
+{
+    fn eq(&self, other: &[U]) -> bool
+    {
+        self.as_strided().eq(other)
+    }
+}
+impl<'a, T, U, const N: usize> PartialEq<[U; N]> for StridedMut<'a, T>
+where
+    T: PartialEq<U>
+{
+    fn eq(&self, other: &[U; N]) -> bool
+    {
+        self.as_strided().eq(other)
+    }
+}
+impl<'a, T> Eq for StridedMut<'a, T>
+where
+    T: Eq
+{
+
+}
+
+impl<'a, T> IntoIterator for Strided<'a, T>
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter()
+    }
+}
+impl<'a, 'b, T> IntoIterator for &'b Strided<'a, T>
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter()
+    }
+}
+impl<'a, T> IntoIterator for StridedMut<'a, T>
+{
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        IterMut {
+            ptr: self.ptr,
+            stride: self.stride,
+            front: 0,
+            back: self.len,
+            marker: PhantomData
+        }
+    }
+}
+impl<'a, 'b, T> IntoIterator for &'b StridedMut<'a, T>
+{
+    type Item = &'b T;
+    type IntoIter = Iter<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter()
+    }
+}
+impl<'a, 'b, T> IntoIterator for &'b mut StridedMut<'a, T>
+{
+    type Item = &'b mut T;
+    type IntoIter = IterMut<'b, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over the elements of a [`Strided`] view.
+pub struct Iter<'a, T>
+{
+    ptr: *const T,
+    stride: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a [T]>
+}
+
+/// An iterator over mutable references to the elements of a [`StridedMut`] view.
+pub struct IterMut<'a, T>
+{
+    ptr: *mut T,
+    stride: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a mut [T]>
+}
+
+unsafe impl<'a, T> Send for Iter<'a, T>
+where
+    T: Sync
+{
+
+}
+unsafe impl<'a, T> Sync for Iter<'a, T>
+where
+    T: Sync
+{
+
+}
+unsafe impl<'a, T> Send for IterMut<'a, T>
+where
+    T: Send
+{
+
+}
+unsafe impl<'a, T> Sync for IterMut<'a, T>
+where
+    T: Sync
+{
+
+}
+
+impl<'a, T> Clone for Iter<'a, T>
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            ptr: self.ptr,
+            stride: self.stride,
+            front: self.front,
+            back: self.back,
+            marker: PhantomData
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T>
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.front >= self.back
+        {
+            return None
+        }
+        let i = self.front;
+        self.front += 1;
+        Some(unsafe {
+            &*self.ptr.add(i*self.stride)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item>
+    {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+impl<'a, T> DoubleEndedIterator for Iter<'a, T>
+{
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        if self.front >= self.back
+        {
+            return None
+        }
+        self.back -= 1;
+        Some(unsafe {
+            &*self.ptr.add(self.back*self.stride)
+        })
+    }
+}
+impl<'a, T> ExactSizeIterator for Iter<'a, T>
+{
+
+}
+impl<'a, T> FusedIterator for Iter<'a, T>
+{
+
+}
+
+impl<'a, T> Iterator for IterMut<'a, T>
+{
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.front >= self.back
+        {
+            return None
+        }
+        let i = self.front;
+        self.front += 1;
+        Some(unsafe {
+            &mut *self.ptr.add(i*self.stride)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item>
+    {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T>
+{
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        if self.front >= self.back
+        {
+            return None
+        }
+        self.back -= 1;
+        Some(unsafe {
+            &mut *self.ptr.add(self.back*self.stride)
+        })
+    }
+}
+impl<'a, T> ExactSizeIterator for IterMut<'a, T>
+{
+
+}
+impl<'a, T> FusedIterator for IterMut<'a, T>
+{
+
+}
+
+/// An iterator over the lanes of a slice spread across a runtime number of strided views.
+///
+/// This struct is created by [`SliceSpread::spread_dyn`](crate::ops::SliceSpread::spread_dyn).
+pub struct SpreadDyn<'a, T>
+{
+    ptr: *const T,
+    stride: usize,
+    len: usize,
+    lanes: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a [T]>
+}
+
+/// An iterator over the lanes of a slice spread across a runtime number of mutable strided views.
+///
+/// This struct is created by [`SliceSpread::spread_dyn_mut`](crate::ops::SliceSpread::spread_dyn_mut).
+pub struct SpreadDynMut<'a, T>
+{
+    ptr: *mut T,
+    stride: usize,
+    len: usize,
+    lanes: usize,
+    front: usize,
+    back: usize,
+    marker: PhantomData<&'a mut [T]>
+}
+
+unsafe impl<'a, T> Send for SpreadDyn<'a, T>
+where
+    T: Sync
+{
+
+}
+unsafe impl<'a, T> Sync for SpreadDyn<'a, T>
+where
+    T: Sync
+{
+
+}
+unsafe impl<'a, T> Send for SpreadDynMut<'a, T>
+where
+    T: Send
+{
+
+}
+unsafe impl<'a, T> Sync for SpreadDynMut<'a, T>
+where
+    T: Sync
+{
+
+}
+
+const fn lane_len(len: usize, lanes: usize, i: usize) -> usize
+{
+    len/lanes + if len % lanes > i {1} else {0}
+}
+
+impl<'a, T> SpreadDyn<'a, T>
+{
+    pub(crate) const fn new(slice: &'a [T], lanes: usize) -> Self
+    {
+        Self::from_strided(Strided::new(slice, 0, 1), lanes)
+    }
+
+    pub(crate) const fn from_strided(view: Strided<'a, T>, lanes: usize) -> Self
+    {
+        assert!(lanes != 0, "Cannot spread across zero lanes.");
+        Self {
+            ptr: view.ptr,
+            stride: view.stride,
+            len: view.len,
+            lanes,
+            front: 0,
+            back: lanes,
+            marker: PhantomData
+        }
+    }
+
+    pub(crate) const fn lane(&self, i: usize) -> Strided<'a, T>
+    {
+        let len = lane_len(self.len, self.lanes, i);
+        unsafe {
+            Strided::from_raw_parts(
+                if len > 0 {self.ptr.add(i*self.stride)} else {self.ptr},
+                self.stride.saturating_mul(self.lanes),
+                len
+            )
+        }
+    }
+}
+impl<'a, T> SpreadDynMut<'a, T>
+{
+    pub(crate) const fn new(slice: &'a mut [T], lanes: usize) -> Self
+    {
+        Self::from_strided_mut(StridedMut::new(slice, 0, 1), lanes)
+    }
+
+    pub(crate) const fn from_strided_mut(view: StridedMut<'a, T>, lanes: usize) -> Self
+    {
+        assert!(lanes != 0, "Cannot spread across zero lanes.");
+        Self {
+            ptr: view.ptr,
+            stride: view.stride,
+            len: view.len,
+            lanes,
+            front: 0,
+            back: lanes,
+            marker: PhantomData
+        }
+    }
+
+    /// # Safety
+    ///
+    /// Each lane must be taken at most once, since the views would otherwise alias.
+    pub(crate) const unsafe fn lane(&self, i: usize) -> StridedMut<'a, T>
+    {
+        let len = lane_len(self.len, self.lanes, i);
+        unsafe {
+            StridedMut::from_raw_parts(
+                if len > 0 {self.ptr.add(i*self.stride)} else {self.ptr},
+                self.stride.saturating_mul(self.lanes),
+                len
+            )
+        }
+    }
+}
+
+impl<'a, T> Clone for SpreadDyn<'a, T>
+{
+    fn clone(&self) -> Self
+    {
+        Self {
+            ptr: self.ptr,
+            stride: self.stride,
+            len: self.len,
+            lanes: self.lanes,
+            front: self.front,
+            back: self.back,
+            marker: PhantomData
+        }
+    }
+}
+
+impl<'a, T> Iterator for SpreadDyn<'a, T>
+{
+    type Item = Strided<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.front >= self.back
+        {
+            return None
+        }
+        let i = self.front;
+        self.front += 1;
+        Some(self.lane(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+}
+impl<'a, T> DoubleEndedIterator for SpreadDyn<'a, T>
+{
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        if self.front >= self.back
+        {
+            return None
+        }
+        self.back -= 1;
+        Some(self.lane(self.back))
+    }
+}
+impl<'a, T> ExactSizeIterator for SpreadDyn<'a, T>
+{
+
+}
+impl<'a, T> FusedIterator for SpreadDyn<'a, T>
+{
+
+}
+
+impl<'a, T> Iterator for SpreadDynMut<'a, T>
+{
+    type Item = StridedMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.front >= self.back
+        {
+            return None
+        }
+        let i = self.front;
+        self.front += 1;
+        Some(unsafe {
+            self.lane(i)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+}
+impl<'a, T> DoubleEndedIterator for SpreadDynMut<'a, T>
+{
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        if self.front >= self.back
+        {
+            return None
+        }
+        self.back -= 1;
+        Some(unsafe {
+            self.lane(self.back)
+        })
+    }
+}
+impl<'a, T> ExactSizeIterator for SpreadDynMut<'a, T>
+{
+
+}
+impl<'a, T> FusedIterator for SpreadDynMut<'a, T>
+{
+
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::ops::SliceSpread;
+
+    use super::{Strided, StridedMut};
+
+    #[test]
+    fn it_works()
+    {
+        let arr = [1, 2, 3, 4, 5, 6, 7];
+
+        assert_eq!(Strided::new(&arr, 0, 3), [1, 4, 7]);
+        assert_eq!(Strided::new(&arr, 2, 3), [3, 6]);
+        assert!(Strided::new(&arr, 7, 3).is_empty());
+        assert_eq!(Strided::new(&arr, 0, 3).iter().rev().copied().collect::<Vec<_>>(), [7, 4, 1]);
+        assert_eq!(Strided::new(&arr, 0, 3).argmax(), Some(2));
+    }
+
+    #[test]
+    fn spread_dyn()
+    {
+        let mut arr: Vec<usize> = (0..23).collect();
+        for m in 1..30
+        {
+            let lanes: Vec<_> = arr.spread_dyn(m).collect();
+            assert_eq!(lanes.len(), m);
+            for (i, lane) in lanes.into_iter().enumerate()
+            {
+                assert_eq!(lane.iter().copied().collect::<Vec<_>>(), (i..arr.len()).step_by(m).collect::<Vec<_>>());
+            }
+        }
+
+        for (i, mut lane) in arr.spread_dyn_mut(4).enumerate()
+        {
+            lane.mul_assign_all(i);
+        }
+        assert!(arr.iter().enumerate().all(|(j, &x)| x == j*(j % 4)));
+
+        let mut lane = StridedMut::new(&mut arr, 1, 4);
+        lane[0] = 100;
+        assert_eq!(arr[1], 100);
+    }
+
+    #[test]
+    fn spread_of_spread()
+    {
+        let mut arr: Vec<usize> = (0..29).collect();
+
+        for (i, lane) in arr.spread::<3>().into_iter().enumerate()
+        {
+            for (j, sublane) in lane.spread_dyn(4).enumerate()
+            {
+                assert!(sublane.iter().copied().eq((i + 3*j..29).step_by(12)));
+            }
+        }
+
+        let [_, mut odd] = arr.spread_mut();
+        let [mut a, _] = odd.spread_mut();
+        a.not_assign_all();
+        assert!(arr.iter().enumerate().all(|(i, &x)| x == if i % 4 == 1 {!i} else {i}));
+    }
+}